@@ -13,7 +13,7 @@ pub struct Weight<T: ?Sized> {
 }
 
 fn indexed<'a, T>(items: &'a [T], state: &mut State) -> Option<&'a T> {
-    if items.len() == 0 {
+    if items.is_empty() {
         None
     } else {
         Some(&items[state.random.usize(0..items.len())])
@@ -271,7 +271,7 @@ impl<K: Ord + Clone, V: Generate> Generate for BTreeMap<K, V> {
     type Shrink = Shrinker<(Constant<K>, V::Shrink), Self::Item>;
     fn generate(&self, state: &mut State) -> (Self::Item, Self::Shrink) {
         Generator::new(
-            self.into_iter()
+            self.iter()
                 .map(|(key, value)| (Constant(key.clone()), value)),
         )
         .generate(state)
@@ -330,7 +330,7 @@ impl<K: Eq + Hash + Clone, V: Generate, S: BuildHasher + Default> Generate for H
     type Shrink = Shrinker<(Constant<K>, V::Shrink), Self::Item>;
     fn generate(&self, state: &mut State) -> (Self::Item, Self::Shrink) {
         Generator::new(
-            self.into_iter()
+            self.iter()
                 .map(|(key, value)| (Constant(key.clone()), value)),
         )
         .generate(state)
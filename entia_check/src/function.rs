@@ -8,7 +8,7 @@ impl<T> Generate for fn() -> T {
     type Shrink = Self;
 
     fn generate(&self, _: &mut State) -> (Self::Item, Self::Shrink) {
-        (self(), self.clone())
+        (self(), *self)
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::{
     any::Any, array::Array, collect::Collect, filter::Filter, filter_map::FilterMap,
-    flatten::Flatten, keep::Keep, map::Map, primitive::Range, tuples, sample::Sample,
-    shrink::Shrink, size::Size,
+    flatten::Flatten, keep::Keep, map::Map, primitive::Range, sample::Sample, shrink::Shrink,
+    size::Size, tuples,
 };
 use fastrand::Rng;
 use std::iter::FromIterator;
@@ -101,7 +101,7 @@ pub trait Generate {
         Self: Sized,
         Collect<Self, Size<Range<usize>>, F>: Generate,
     {
-        self.collect_with((0..256 as usize).generator())
+        self.collect_with((0..256_usize).generator())
     }
 
     fn collect_with<C: Generate<Item = usize>, F: FromIterator<Self::Item>>(
@@ -131,7 +131,7 @@ pub trait Generate {
         Keep(self)
     }
 
-    fn sample(&self, count: usize) -> Sample<Self>
+    fn sample(&self, count: usize) -> Sample<'_, Self>
     where
         Self: Sized,
     {
@@ -184,7 +184,7 @@ impl<G: Generate> Generate for &G {
     type Item = G::Item;
     type Shrink = G::Shrink;
     fn generate(&self, state: &mut State) -> (Self::Item, Self::Shrink) {
-        (&**self).generate(state)
+        (**self).generate(state)
     }
 }
 
@@ -192,7 +192,7 @@ impl<G: Generate> Generate for &mut G {
     type Item = G::Item;
     type Shrink = G::Shrink;
     fn generate(&self, state: &mut State) -> (Self::Item, Self::Shrink) {
-        (&**self).generate(state)
+        (**self).generate(state)
     }
 }
 
@@ -201,7 +201,7 @@ macro_rules! tuple {
         impl FullGenerate for () {
             type Item = <Self::Generate as Generate>::Item;
             type Generate = ();
-            fn generator() -> Self::Generate { () }
+            fn generator() -> Self::Generate {}
         }
 
         impl IntoGenerate for () {
@@ -218,7 +218,7 @@ macro_rules! tuple {
 
         impl Shrink for () {
             type Item = ();
-            fn generate(&self) -> Self::Item { () }
+            fn generate(&self) -> Self::Item {}
             fn shrink(&mut self) -> Option<Self> { None }
         }
     };
@@ -326,7 +326,7 @@ mod boolean {
 
         fn shrink(&mut self) -> Option<Self> {
             if self.0.change(false) {
-                Some(self.clone())
+                Some(*self)
             } else {
                 None
             }
@@ -448,7 +448,7 @@ mod character {
         type Shrink = Shrinker;
 
         fn generate(&self, state: &mut State) -> (Self::Item, Self::Shrink) {
-            let (item, shrink) = Into::<Range<u32>>::into(self.clone()).generate(state);
+            let (item, shrink) = Into::<Range<u32>>::into(*self).generate(state);
             (item.try_into().unwrap(), Shrinker(shrink))
         }
     }
@@ -458,7 +458,7 @@ mod character {
         type Shrink = Shrinker;
 
         fn generate(&self, state: &mut State) -> (Self::Item, Self::Shrink) {
-            let (item, shrink) = Into::<Range<u32>>::into(self.deref().clone())
+            let (item, shrink) = Into::<Range<u32>>::into(*self.deref())
                 .size()
                 .generate(state);
             (item.try_into().unwrap(), Shrinker(shrink))
@@ -757,6 +757,6 @@ mod number {
     }
 
     integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
-    floating!(f32, 3.45266984e-4);
+    floating!(f32, 3.452_669_8e-4);
     floating!(f64, 1.4901161193847656e-8);
 }
@@ -1,3 +1,6 @@
+// The errors carry the shrunk values that failed the checks.
+#![allow(clippy::result_large_err)]
+
 pub mod boolean;
 pub mod character;
 pub mod number;
@@ -15,4 +15,4 @@ macro_rules! tuple {
     };
 }
 
-tuples_with!(tuple);
\ No newline at end of file
+tuples_with!(tuple);
@@ -50,7 +50,10 @@ impl<B, T> Mut<B, T> {
         B: 'a,
         T: 'a,
     {
-        Self(borrow(unsafe { transmute(&mut value) }), value)
+        Self(
+            borrow(unsafe { transmute::<&mut T, &'a mut T>(&mut value) }),
+            value,
+        )
     }
 
     #[inline]
@@ -59,7 +62,7 @@ impl<B, T> Mut<B, T> {
         B: 'a,
         T: 'a,
     {
-        match borrow(unsafe { transmute(&mut value) }) {
+        match borrow(unsafe { transmute::<&mut T, &'a mut T>(&mut value) }) {
             Some(borrow) => Ok(Self(borrow, value)),
             None => Err(value),
         }
@@ -106,21 +109,21 @@ impl<B, T> Mut<B, T> {
 
 impl<B, T> Ref<B, T> {
     #[inline]
-    pub fn new<'a>(mut value: T, borrow: impl FnOnce(&'a T) -> B) -> Self
+    pub fn new<'a>(value: T, borrow: impl FnOnce(&'a T) -> B) -> Self
     where
         B: 'a,
         T: 'a,
     {
-        Self(borrow(unsafe { transmute(&value) }), value)
+        Self(borrow(unsafe { transmute::<&T, &'a T>(&value) }), value)
     }
 
     #[inline]
-    pub fn try_new<'a>(mut value: T, borrow: impl FnOnce(&'a T) -> Option<B>) -> Result<Self, T>
+    pub fn try_new<'a>(value: T, borrow: impl FnOnce(&'a T) -> Option<B>) -> Result<Self, T>
     where
         B: 'a,
         T: 'a,
     {
-        match borrow(unsafe { transmute(&value) }) {
+        match borrow(unsafe { transmute::<&T, &'a T>(&value) }) {
             Some(borrow) => Ok(Self(borrow, value)),
             None => Err(value),
         }
@@ -190,8 +193,6 @@ impl<B, T> DerefMut for Mut<B, T> {
 
 #[cfg(test)]
 mod tests {
-    use std::ops::DerefMut;
-
     use super::Mut;
 
     #[test]
@@ -1,3 +1,6 @@
+// Values are named after their position.
+#![allow(clippy::just_underscores_and_digits)]
+
 use std::{
     array, iter,
     mem::replace,
@@ -35,6 +38,10 @@ impl<T> Few<T> {
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Few::Zero) || matches!(self, Few::More(values) if values.is_empty())
+    }
+
     pub fn push(&mut self, value: T) {
         *self = match replace(self, Few::Zero) {
             Few::Zero => Few::One([value]),
@@ -118,11 +118,6 @@ impl<T, R: IntoFlags<T> + Clone, F: PartialEq<R::Value>> PartialEq<R> for Flags<
     fn eq(&self, other: &R) -> bool {
         self.0.eq(&other.flags().0)
     }
-
-    #[inline]
-    fn ne(&self, other: &R) -> bool {
-        self.0.ne(&other.flags().0)
-    }
 }
 
 impl<T, F: PartialEq<Self> + Eq + Clone> Eq for Flags<T, F> {}
@@ -1,3 +1,6 @@
+// Unsafe functions describe their contract in a 'SAFETY:' comment.
+#![allow(clippy::missing_safety_doc)]
+
 pub mod append;
 pub mod array;
 pub mod bits;
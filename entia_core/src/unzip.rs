@@ -12,6 +12,7 @@ macro_rules! tuple {
             type Target = ($([$t; N],)*);
 
             #[inline]
+            #[allow(clippy::unused_unit)]
             fn unzip(self) -> Self::Target {
                 $(let mut $p = MaybeUninit::<[$t; N]>::uninit();)*
                 {
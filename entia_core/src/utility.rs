@@ -11,7 +11,11 @@ pub const fn next_power_of_2(mut value: u32) -> u32 {
 }
 
 pub fn short_type_name<T: ?Sized>() -> String {
-    let name = type_name::<T>();
+    short_name(type_name::<T>())
+}
+
+/// Removes the module paths of the type `name`.
+pub fn short_name(name: &str) -> String {
     let mut buffer = String::with_capacity(name.len());
     let mut checkpoint = 0;
     let mut characters = name.chars();
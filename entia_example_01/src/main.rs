@@ -1,6 +1,9 @@
 use entia::{error::Error, *};
 use piston::WindowSettings;
-use piston_window::*;
+use piston_window::{
+    graphics::{rectangle, Graphics, Rectangle, Transformed},
+    *,
+};
 use std::{collections::VecDeque, error, result::Result, time::Duration};
 
 /*
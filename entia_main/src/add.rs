@@ -1,3 +1,6 @@
+// Work in progress: the declarations and imports are kept for the commented out implementation below.
+#![allow(dead_code, unused_imports, unused_variables)]
+
 use entia_core::FullIterator;
 
 use crate::{
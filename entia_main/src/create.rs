@@ -3,15 +3,18 @@ use crate::{
     depend::{Dependency, Order},
     entities::{Datum, Entities},
     entity::Entity,
-    error::Result,
+    error::{Error, Result},
     family::template::{EntityIndices, Families, Family, SegmentIndices},
     inject::{Adapt, Context, Get, Inject},
     meta::Metas,
     resource::{Read, Write},
     segment::Segments,
-    template::{ApplyContext, CountContext, DeclareContext, InitializeContext, Spawn, Template},
+    template::{
+        self, ApplyContext, CountContext, DeclareContext, InitializeContext, Spawn, Template,
+    },
 };
 use entia_core::FullIterator;
+use parking_lot::Mutex;
 use std::collections::HashMap;
 
 pub struct Create<'a, T: Template + 'a> {
@@ -27,6 +30,9 @@ struct Outer<T: Template> {
     entities: Write<Entities>,
     metas: Write<Metas>,
     segments: Write<Segments>,
+    /// Whether the roots are applied by one run per segment after the resolution such that only the resolution of
+    /// `Entities` is serialized with the other creations.
+    parallel: bool,
 }
 
 struct Inner<T: Template> {
@@ -38,6 +44,18 @@ struct Inner<T: Template> {
     initial_state: <Spawn<T> as Template>::State,
     initial_roots: Vec<Spawn<T>>,
     initialize: Vec<(u32, Datum)>,
+    /// The errors of the templates that are returned when the creation resolves.
+    error: Option<Error>,
+    /// Each slot is only locked by the run of its segment such that the runs never wait on each other.
+    segment_roots: Box<[Mutex<SegmentRoots<T>>]>,
+    deferred: Vec<Defer<T>>,
+}
+
+/// Roots of which the whole hierarchy belongs to the same segment. The last one holds the roots that span many segments.
+struct SegmentRoots<T: Template> {
+    roots: Vec<(usize, Spawn<T>, (usize, usize))>,
+    initialize: Vec<(u32, Datum)>,
+    error: Option<Error>,
 }
 
 struct Defer<T: Template> {
@@ -56,22 +74,25 @@ TODO: Add a resolve parameter to `Create<'a, T, R = Early>` to allow resolving c
 */
 
 impl<T: Template> Create<'_, T> {
-    pub fn all(&mut self, templates: impl IntoIterator<Item = T>) -> Families {
+    /// The templates that fail to be counted (see `CountContext::fail`) are not created and their errors are returned
+    /// when the creation resolves along with the errors of the templates that fail to apply.
+    pub fn all(&mut self, templates: impl IntoIterator<Item = T>) -> Families<'_> {
         match self.inner.count {
             Some(count) => self.all_static(count, templates),
             None => self.all_dynamic(templates),
         }
     }
 
+    /// Panics if the `template` fails to be counted (see `Create::all`).
     #[inline]
-    pub fn one(&mut self, template: T) -> Family {
+    pub fn one(&mut self, template: T) -> Family<'_> {
         self.all([template])
             .get(0)
             .expect("There must be have at least one root.")
     }
 
     #[inline]
-    pub fn clones(&mut self, count: usize, template: T) -> Families
+    pub fn clones(&mut self, count: usize, template: T) -> Families<'_>
     where
         T: Clone,
     {
@@ -79,14 +100,14 @@ impl<T: Template> Create<'_, T> {
     }
 
     #[inline]
-    pub fn defaults(&mut self, count: usize) -> Families
+    pub fn defaults(&mut self, count: usize) -> Families<'_>
     where
         T: Default,
     {
         self.all((0..count).map(|_| T::default()))
     }
 
-    fn all_static(&mut self, count: usize, templates: impl IntoIterator<Item = T>) -> Families {
+    fn all_static(&mut self, count: usize, templates: impl IntoIterator<Item = T>) -> Families<'_> {
         let Create {
             defer,
             inner,
@@ -105,7 +126,7 @@ impl<T: Template> Create<'_, T> {
         inner.apply_or_defer(count * inner.initial_roots.len(), defer, entities, segments)
     }
 
-    fn all_dynamic(&mut self, templates: impl IntoIterator<Item = T>) -> Families {
+    fn all_dynamic(&mut self, templates: impl IntoIterator<Item = T>) -> Families<'_> {
         let Create {
             defer,
             inner,
@@ -121,7 +142,8 @@ impl<T: Template> Create<'_, T> {
             .for_each(|indices| indices.count = 0);
 
         for template in templates {
-            inner.entity_roots.push((0, inner.entity_indices.len()));
+            let index = inner.entity_indices.len();
+            let mut error = None;
             let root = Spawn::new(template);
             root.dynamic_count(
                 &inner.initial_state,
@@ -129,9 +151,16 @@ impl<T: Template> Create<'_, T> {
                     &mut inner.segment_indices,
                     &mut None,
                     &mut inner.entity_indices,
+                    &mut error,
                 ),
             );
-            inner.initial_roots.push(root);
+            match error {
+                Some(error) => inner.discard(index, error),
+                None => {
+                    inner.entity_roots.push((0, index));
+                    inner.initial_roots.push(root);
+                }
+            }
         }
 
         inner.apply_or_defer(inner.entity_indices.len(), defer, entities, segments)
@@ -139,13 +168,25 @@ impl<T: Template> Create<'_, T> {
 }
 
 impl<T: Template> Inner<T> {
+    /// Removes the entities that were counted from the `index` on.
+    fn discard(&mut self, index: usize, error: Error) {
+        self.entity_indices.truncate(index);
+        for indices in self.segment_indices.iter_mut() {
+            indices.count = 0;
+        }
+        for indices in self.entity_indices.iter() {
+            self.segment_indices[indices.segment].count += 1;
+        }
+        template::fail(&mut self.error, error);
+    }
+
     fn apply_or_defer(
         &mut self,
         count: usize,
         defer: &mut defer::Defer<Outer<T>>,
         entities: &Entities,
         segments: &Segments,
-    ) -> Families {
+    ) -> Families<'_> {
         if count == 0 {
             return Families::EMPTY;
         }
@@ -159,6 +200,7 @@ impl<T: Template> Inner<T> {
                 &self.entity_indices,
                 &self.segment_indices,
                 &mut self.initialize,
+                &mut self.error,
             ),
             (index, false) => defer.one(Defer {
                 index,
@@ -178,6 +220,44 @@ impl<T: Template> Inner<T> {
         )
     }
 
+    /// Hands the roots to the run of their segment.
+    fn defer_segments(&mut self, mut defer: Defer<T>) {
+        let index = self.deferred.len();
+        for (root, &entity_root) in defer.initial_roots.drain(..).zip(defer.entity_roots.iter()) {
+            let segment =
+                segment(&defer.entity_indices, entity_root.1).unwrap_or(self.segment_indices.len());
+            self.segment_roots[segment]
+                .get_mut()
+                .roots
+                .push((index, root, entity_root));
+        }
+        self.deferred.push(defer);
+    }
+
+    /// Since the runs of the segments execute concurrently, only the slot of the `segment` is mutated.
+    fn apply_segment(&self, segment: usize) {
+        let mut slot = self.segment_roots[segment].lock();
+        let SegmentRoots {
+            roots,
+            initialize,
+            error,
+        } = &mut *slot;
+        for (index, root, entity_root) in roots.drain(..) {
+            let defer = &self.deferred[index];
+            root.apply(
+                &self.initial_state,
+                ApplyContext::new(
+                    entity_root,
+                    &defer.entity_instances,
+                    &defer.entity_indices,
+                    &defer.segment_indices,
+                    initialize,
+                    error,
+                ),
+            );
+        }
+    }
+
     fn reserve(&mut self, count: usize, entities: &Entities, segments: &Segments) -> (usize, bool) {
         self.entity_instances.resize(count, Entity::NULL);
         let ready = entities.reserve(&mut self.entity_instances);
@@ -229,7 +309,13 @@ where
         let mut segments =
             Write::initialize(None, context.map(|state| &mut state.0.as_mut().segments))?;
         let mut segment_metas = Vec::new();
-        let initial = Spawn::<T>::declare(DeclareContext::new(0, &mut segment_metas, &mut metas));
+        let world = context.world().identifier();
+        let initial = Spawn::<T>::declare(DeclareContext::new(
+            world,
+            0,
+            &mut segment_metas,
+            &mut metas,
+        ));
         let mut segment_to_index = HashMap::new();
         let mut metas_to_segment = HashMap::new();
         let mut segment_indices = Vec::with_capacity(segment_metas.len());
@@ -262,7 +348,12 @@ where
         let mut entity_indices = Vec::new();
         let count = if Spawn::<T>::static_count(
             &state,
-            CountContext::new(&mut segment_indices, &mut None, &mut entity_indices),
+            CountContext::new(
+                &mut segment_indices,
+                &mut None,
+                &mut entity_indices,
+                &mut None,
+            ),
         )? {
             Some(entity_indices.len())
         } else {
@@ -276,19 +367,89 @@ where
             entity_indices,
             entity_instances: Vec::new(),
             entity_roots: Vec::new(),
+            segment_roots: (0..=segment_indices.len())
+                .map(|_| {
+                    Mutex::new(SegmentRoots {
+                        roots: Vec::new(),
+                        initialize: Vec::new(),
+                        error: None,
+                    })
+                })
+                .collect(),
             segment_indices,
             initialize: Vec::new(),
+            error: None,
+            deferred: Vec::new(),
         };
         let outer = Outer {
             inner,
             metas,
             entities,
             segments,
+            parallel: false,
         };
-        Ok(State(defer::Defer::initialize(
-            outer,
-            context.map(|state| &mut state.0),
-        )?))
+        let defer = defer::Defer::initialize(outer, context.map(|state| &mut state.0))?;
+
+        context.schedule(|State(state), mut schedule| {
+            // Other deferred operations of the system may depend on the created entities so they must be fully applied
+            // by the resolution.
+            let shared = state.shared();
+            let outer = state.as_mut();
+            outer.parallel = !shared;
+            if !outer.parallel {
+                return;
+            }
+
+            let count = outer.inner.segment_indices.len();
+            for index in 0..=count {
+                if index == count && count <= 1 {
+                    break;
+                }
+
+                let mut dependencies = Read::depend(&outer.entities.read());
+                let segments = if index < count {
+                    &outer.inner.segment_indices[index..=index]
+                } else {
+                    &outer.inner.segment_indices[..]
+                };
+                for &SegmentIndices { segment, .. } in segments {
+                    dependencies.push(Dependency::write_at(
+                        outer.segments[segment].identifier(),
+                        Order::Relax,
+                    ));
+                }
+                schedule.post(
+                    move |State(state)| {
+                        state.as_ref().inner.apply_segment(index);
+                        Ok(())
+                    },
+                    dependencies,
+                );
+            }
+            schedule.post(
+                |State(state)| {
+                    let Outer {
+                        inner, entities, ..
+                    } = state.as_mut();
+                    let mut error = None;
+                    for segment_roots in inner.segment_roots.iter_mut() {
+                        let segment_roots = segment_roots.get_mut();
+                        for (index, datum) in segment_roots.initialize.drain(..) {
+                            entities.initialize(index, datum);
+                        }
+                        if let Some(segment_error) = segment_roots.error.take() {
+                            template::fail(&mut error, segment_error);
+                        }
+                    }
+                    inner.deferred.clear();
+                    error.map_or(Ok(()), Err)
+                },
+                Write::depend(&outer.entities)
+                    .into_iter()
+                    .map(|dependency| dependency.relax()),
+            );
+        });
+        Ok(State(defer))
     }
 
     fn depend(State(state): &Self::State) -> Vec<Dependency> {
@@ -334,7 +495,7 @@ unsafe impl<T: Template> Resolve for Outer<T> {
         for (index, datum) in self.inner.initialize.drain(..) {
             self.entities.initialize(index, datum);
         }
-        Ok(())
+        self.inner.error.take().map_or(Ok(()), Err)
     }
 
     fn resolve(&mut self, items: impl FullIterator<Item = Self::Item>) -> Result {
@@ -359,15 +520,20 @@ unsafe impl<T: Template> Resolve for Outer<T> {
                 };
             }
 
-            apply(
-                &inner.initial_state,
-                &mut defer.initial_roots,
-                &defer.entity_roots,
-                &defer.entity_instances,
-                &defer.entity_indices,
-                &defer.segment_indices,
-                &mut inner.initialize,
-            );
+            if self.parallel {
+                inner.defer_segments(defer);
+            } else {
+                apply(
+                    &inner.initial_state,
+                    &mut defer.initial_roots,
+                    &defer.entity_roots,
+                    &defer.entity_instances,
+                    &defer.entity_indices,
+                    &defer.segment_indices,
+                    &mut inner.initialize,
+                    &mut inner.error,
+                );
+            }
         }
         Ok(())
     }
@@ -400,6 +566,7 @@ unsafe impl<T: Template> Resolve for Outer<T> {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn apply<T: Template>(
     initial_state: &<Spawn<T> as Template>::State,
     initial_roots: &mut Vec<Spawn<T>>,
@@ -408,6 +575,7 @@ fn apply<T: Template>(
     entity_indices: &[EntityIndices],
     segment_indices: &[SegmentIndices],
     initialize: &mut Vec<(u32, Datum)>,
+    error: &mut Option<Error>,
 ) {
     for (root, &entity_root) in initial_roots.drain(..).zip(entity_roots) {
         root.apply(
@@ -418,11 +586,22 @@ fn apply<T: Template>(
                 entity_indices,
                 segment_indices,
                 initialize,
+                error,
             ),
         );
     }
 }
 
+/// Returns the segment of the hierarchy that starts at `entity_index` if all of its entities belong to it.
+fn segment(entity_indices: &[EntityIndices], entity_index: usize) -> Option<usize> {
+    let segment = entity_indices[entity_index].segment;
+    entity_indices[entity_index + 1..]
+        .iter()
+        .take_while(|indices| indices.parent.is_some_and(|parent| parent >= entity_index))
+        .all(|indices| indices.segment == segment)
+        .then_some(segment)
+}
+
 impl<'a, T: Template + 'static> Get<'a> for State<T> {
     type Item = Create<'a, T>;
 
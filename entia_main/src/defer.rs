@@ -1,16 +1,15 @@
 use crate::{
-    depend::{Dependency, Order},
+    depend::{merge, Dependency, Order},
     error::{Error, Result},
     identify,
     inject::{Adapt, Context, Get, Inject},
-    resource::Resource,
     resource::{Read, Write},
+    Resource,
 };
-use entia_core::FullIterator;
+use entia_core::{utility::short_type_name, FullIterator};
 use std::{
     any::Any,
-    cmp::max,
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{HashMap, VecDeque},
     iter::once,
     marker::PhantomData,
     sync::atomic::{AtomicUsize, Ordering},
@@ -37,6 +36,7 @@ struct Resolver {
     depend: fn(&dyn Any) -> Vec<Dependency>,
 }
 
+#[derive(Resource, Default)]
 struct Outer {
     indices: HashMap<usize, usize>,
     inners: Vec<Inner>,
@@ -67,17 +67,6 @@ pub unsafe trait Resolve {
     }
 }
 
-impl Default for Outer {
-    fn default() -> Self {
-        Outer {
-            indices: HashMap::new(),
-            inners: Vec::new(),
-        }
-    }
-}
-
-impl Resource for Outer {}
-
 #[allow(type_alias_bounds)]
 type Triple<R: Resolve> = (R, Vec<(usize, usize)>, VecDeque<R::Item>);
 
@@ -178,8 +167,11 @@ where
                 None => {
                     let index = outer.inners.len();
                     outer.indices.insert(identifier, index);
+                    let resolution = identify();
+                    let descriptions = context.world().descriptions();
+                    descriptions.describe(resolution, "deferred operations resolution");
                     outer.inners.push(Inner {
-                        identifier: identify(),
+                        identifier: resolution,
                         reserved: AtomicUsize::new(0),
                         resolved: 0,
                         indices: Vec::new(),
@@ -193,7 +185,12 @@ where
         let resolver = {
             let inner = &mut outer.inners[inner];
             let index = inner.resolvers.len();
-            inner.resolvers.push(Resolver::new(input));
+            let resolver = Resolver::new(input);
+            context.world().descriptions().describe(
+                resolver.identifier,
+                format!("deferred operations of `{}`", short_type_name::<R>()),
+            );
+            inner.resolvers.push(resolver);
             index
         };
 
@@ -224,7 +221,7 @@ where
                     // This check is fine since the only way there could be pending items in `inner.indices` is if they were waiting on
                     // another item to be resolved, thus only a defer with `items.len() > 0` could be blocking and will be responsible
                     // for resolving the pending items. Having no `items` also means that `indices` is empty.
-                    if items.len() == 0 {
+                    if items.is_empty() {
                         resolver.post()?;
                         return Ok(());
                     } else if resolvers <= 1 {
@@ -295,6 +292,14 @@ where
     }
 }
 
+impl<T> State<T> {
+    /// Whether other deferred operations of the same system resolve along with this one.
+    #[inline]
+    pub(crate) fn shared(&self) -> bool {
+        self.outer.inners[self.inner].resolvers.len() > 1
+    }
+}
+
 impl<'a, R: Resolve + 'static> Get<'a> for State<R> {
     type Item = (Defer<'a, R>, &'a mut R);
 
@@ -330,52 +335,82 @@ impl<R: Resolve + 'static> AsMut<R> for State<R> {
     }
 }
 
-/// Combines dependencies in a way that removes inner conflicts between resolvers (keep the stricter dependencies).
-fn merge<D: IntoIterator<Item = Dependency>, I: IntoIterator<Item = D>>(
-    dependencies: &mut Vec<Dependency>,
-    inputs: I,
-) {
-    let mut reads = HashMap::new();
-    let mut writes = HashMap::new();
-    let mut current = HashSet::new();
-    for input in inputs {
-        current.clear();
-        for dependency in input {
-            match dependency {
-                Dependency::Read(key, order) if current.insert(key) => match writes.get_mut(&key) {
-                    Some(write) => {
-                        *write = match reads.remove(&key) {
-                            Some(read) => max(max(order, read), *write),
-                            None => max(order, *write),
-                        }
-                    }
-                    None => match reads.insert(key, order) {
-                        Some(read) if read > order => {
-                            reads.insert(key, read);
-                        }
-                        Some(_) | None => {}
-                    },
-                },
-                Dependency::Write(key, order) if current.insert(key) => {
-                    match writes.insert(key, order) {
-                        Some(write) if write > order => {
-                            writes.insert(key, write);
-                        }
-                        Some(_) | None => {}
-                    }
-                }
-                dependency => dependencies.push(dependency),
-            }
+/// Safe deferred operations defined outside of this crate. An `Operation` only accesses the world through the
+/// injectable `Inject` such that the dependencies of its resolution are always the declared ones and its items resolve
+/// in the same order as the ones of the other deferred operations (such as `Create` and `Destroy`) of a system.
+pub mod operation {
+    use super::*;
+
+    pub trait Operation: Send + Sync + 'static {
+        type Item: Send + Sync + 'static;
+        type Inject: Inject;
+
+        fn resolve(
+            &mut self,
+            items: impl FullIterator<Item = Self::Item>,
+            inject: <<Self::Inject as Inject>::State as Get<'_>>::Item,
+        ) -> Result;
+    }
+
+    pub struct Deferred<'a, O: Operation>(Defer<'a, Inner<O>>);
+    pub struct State<O: Operation>(super::State<Inner<O>>);
+    struct Inner<O: Operation>(O, <O::Inject as Inject>::State);
+
+    impl<O: Operation> Deferred<'_, O> {
+        #[inline]
+        pub fn one(&mut self, item: O::Item) {
+            self.0.one(item);
+        }
+
+        #[inline]
+        pub fn all(&mut self, items: impl IntoIterator<Item = O::Item>) {
+            self.0.all(items);
+        }
+    }
+
+    unsafe impl<O: Operation> Inject for Deferred<'_, O>
+    where
+        <O::Inject as Inject>::Input: Default,
+    {
+        type Input = O;
+        type State = State<O>;
+
+        fn initialize<A: Adapt<Self::State>>(
+            input: Self::Input,
+            mut context: Context<Self::State, A>,
+        ) -> Result<Self::State> {
+            let inject = O::Inject::initialize(
+                Default::default(),
+                context.map(|state| &mut state.0.as_mut().1),
+            )?;
+            let defer = Defer::initialize(Inner(input, inject), context.map(|state| &mut state.0))?;
+            Ok(State(defer))
+        }
+
+        fn depend(State(state): &Self::State) -> Vec<Dependency> {
+            Defer::depend(state)
+        }
+    }
+
+    unsafe impl<O: Operation> Resolve for Inner<O> {
+        type Item = O::Item;
+
+        fn resolve(&mut self, items: impl FullIterator<Item = Self::Item>) -> Result {
+            // The resolution run holds the dependencies of `O::Inject`.
+            self.0.resolve(items, unsafe { self.1.get() })
+        }
+
+        fn depend(&self) -> Vec<Dependency> {
+            O::Inject::depend(&self.1)
+        }
+    }
+
+    impl<'a, O: Operation> Get<'a> for State<O> {
+        type Item = Deferred<'a, O>;
+
+        #[inline]
+        unsafe fn get(&'a mut self) -> Self::Item {
+            Deferred(self.0.get().0)
         }
     }
-    dependencies.extend(
-        reads
-            .into_iter()
-            .map(|pair| Dependency::Read(pair.0, pair.1)),
-    );
-    dependencies.extend(
-        writes
-            .into_iter()
-            .map(|pair| Dependency::Write(pair.0, pair.1)),
-    );
 }
@@ -1,7 +1,10 @@
 use crate::error::{self, Result};
 use std::{
-    any::TypeId,
+    any::{type_name, TypeId},
+    cmp::{max, Ordering},
     collections::{HashMap, HashSet},
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
     result,
 };
 
@@ -21,6 +24,8 @@ pub enum Order {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Dependency {
     Unknown,
+    /// The run must execute on the thread that calls `Runner::run`.
+    Thread,
     Read(Key, Order),
     Write(Key, Order),
 }
@@ -40,13 +45,107 @@ pub enum Error {
     WriteWriteConflict(Key, Scope, Order),
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A key is compared by identifier only. The name of a `Key::Type` is carried along to name the type in diagnostics
+/// without having to register it anywhere.
+#[derive(Clone, Copy, Debug)]
 pub enum Key {
     At(usize),
-    Type(TypeId),
+    Type(TypeId, &'static str),
 }
 
-error::error!(Error, error::Error::Depend);
+impl std::error::Error for Error {}
+
+impl Error {
+    pub const fn key(&self) -> Option<Key> {
+        match *self {
+            Error::UnknownConflict(_) => None,
+            Error::ReadWriteConflict(key, ..) | Error::WriteWriteConflict(key, ..) => Some(key),
+        }
+    }
+
+    /// Formats the conflict with the `key` in place of its own.
+    pub(crate) fn format(&self, f: &mut fmt::Formatter<'_>, key: impl Display) -> fmt::Result {
+        let scope = |scope| match scope {
+            Scope::Inner => "inner",
+            Scope::Outer => "outer",
+        };
+        let order = |order| match order {
+            Order::Relax => "relaxed",
+            Order::Strict => "strict",
+        };
+        match *self {
+            Error::UnknownConflict(scope_) => write!(f, "{} unknown conflict", scope(scope_)),
+            Error::ReadWriteConflict(_, scope_, order_) => write!(
+                f,
+                "{} {} read/write conflict on {}",
+                scope(scope_),
+                order(order_),
+                key
+            ),
+            Error::WriteWriteConflict(_, scope_, order_) => write!(
+                f,
+                "{} {} write/write conflict on {}",
+                scope(scope_),
+                order(order_),
+                key
+            ),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.key() {
+            Some(key) => self.format(f, key),
+            None => self.format(f, ""),
+        }
+    }
+}
+
+impl PartialEq for Key {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Key::At(left), Key::At(right)) => left.cmp(right),
+            (Key::Type(left, _), Key::Type(right, _)) => left.cmp(right),
+            (Key::At(_), Key::Type(..)) => Ordering::Less,
+            (Key::Type(..), Key::At(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl Hash for Key {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Key::At(identifier) => (0u8, identifier).hash(state),
+            Key::Type(identifier, _) => (1u8, identifier).hash(state),
+        }
+    }
+}
+
+impl From<Error> for error::Error {
+    #[inline]
+    fn from(error: Error) -> Self {
+        error::Error::Depend(error)
+    }
+}
 
 impl Dependency {
     pub const fn read_at(identifier: usize, order: Order) -> Self {
@@ -54,7 +153,7 @@ impl Dependency {
     }
 
     pub fn read<T: 'static>(order: Order) -> Self {
-        Self::Read(Key::Type(TypeId::of::<T>()), order)
+        Self::Read(Key::Type(TypeId::of::<T>(), type_name::<T>()), order)
     }
 
     pub const fn write_at(identifier: usize, order: Order) -> Self {
@@ -62,12 +161,13 @@ impl Dependency {
     }
 
     pub fn write<T: 'static>(order: Order) -> Self {
-        Self::Write(Key::Type(TypeId::of::<T>()), order)
+        Self::Write(Key::Type(TypeId::of::<T>(), type_name::<T>()), order)
     }
 
     pub const fn order(self, order: Order) -> Self {
         match self {
             Self::Unknown => Self::Unknown,
+            Self::Thread => Self::Thread,
             Self::Read(key, _) => Self::Read(key, order),
             Self::Write(key, _) => Self::Write(key, order),
         }
@@ -154,26 +254,31 @@ impl Conflict {
                 }
                 Strict
             }
+            Thread => Strict,
         };
 
         match (dependency, scope, order) {
+            // Runs that are bound to the same thread can not overlap.
+            (Thread, _, _) => Ok(Strict),
             (Unknown, Inner, _) => Ok(Strict),
             (Unknown, Outer, _) => Err(UnknownConflict(scope)),
             (Read(key, _), Outer, Relax) => {
                 if self.writes.contains(key) {
                     Ok(Relax)
-                } else if fill && self.reads.insert(*key) {
-                    Ok(Strict)
                 } else {
+                    if fill {
+                        self.reads.insert(*key);
+                    }
                     Ok(Strict)
                 }
             }
             (Read(key, _), Inner, _) | (Read(key, _), _, Strict) => {
                 if self.writes.contains(key) {
                     Err(ReadWriteConflict(*key, scope, order))
-                } else if fill && self.reads.insert(*key) {
-                    Ok(Strict)
                 } else {
+                    if fill {
+                        self.reads.insert(*key);
+                    }
                     Ok(Strict)
                 }
             }
@@ -202,3 +307,59 @@ impl Conflict {
         }
     }
 }
+
+/// Combines the dependencies of parts of a run that execute one after the other in a way that removes the inner
+/// conflicts between them (keep the stricter dependencies).
+pub(crate) fn merge<D: IntoIterator<Item = Dependency>, I: IntoIterator<Item = D>>(
+    dependencies: &mut Vec<Dependency>,
+    inputs: I,
+) {
+    let mut reads = HashMap::new();
+    let mut writes = HashMap::new();
+    let mut current = HashSet::new();
+    for input in inputs {
+        current.clear();
+        for dependency in input {
+            match dependency {
+                Dependency::Read(key, order) if current.insert(key) => match writes.get_mut(&key) {
+                    Some(write) => {
+                        *write = match reads.remove(&key) {
+                            Some(read) => max(max(order, read), *write),
+                            None => max(order, *write),
+                        }
+                    }
+                    None => match reads.insert(key, order) {
+                        Some(read) if read > order => {
+                            reads.insert(key, read);
+                        }
+                        Some(_) | None => {}
+                    },
+                },
+                Dependency::Write(key, order) if current.insert(key) => {
+                    // A read of a previous part is subsumed by this write.
+                    let order = match reads.remove(&key) {
+                        Some(read) => max(order, read),
+                        None => order,
+                    };
+                    match writes.insert(key, order) {
+                        Some(write) if write > order => {
+                            writes.insert(key, write);
+                        }
+                        Some(_) | None => {}
+                    }
+                }
+                dependency => dependencies.push(dependency),
+            }
+        }
+    }
+    dependencies.extend(
+        reads
+            .into_iter()
+            .map(|pair| Dependency::Read(pair.0, pair.1)),
+    );
+    dependencies.extend(
+        writes
+            .into_iter()
+            .map(|pair| Dependency::Write(pair.0, pair.1)),
+    );
+}
@@ -0,0 +1,99 @@
+use crate::{
+    depend::{self, Key},
+    error::Error,
+    world::World,
+};
+use entia_core::utility::short_name;
+use parking_lot::RwLock;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display},
+};
+
+/// Descriptions of the identifiers of a world that are used as dependency keys. They are held by the `Metas` of the
+/// world and are only looked up when an error is described with `World::describe`.
+#[derive(Debug, Default)]
+pub struct Descriptions(RwLock<HashMap<usize, String>>);
+
+impl Descriptions {
+    /// Associates a `description` with an `identifier`, replacing the previous one (if any).
+    pub fn describe(&self, identifier: usize, description: impl Into<String>) {
+        self.0.write().insert(identifier, description.into());
+    }
+
+    pub fn description(&self, identifier: usize) -> Option<String> {
+        self.0.read().get(&identifier).cloned()
+    }
+
+    pub fn forget(&self, identifier: usize) {
+        self.0.write().remove(&identifier);
+    }
+}
+
+impl World {
+    #[inline]
+    pub fn descriptions(&mut self) -> &Descriptions {
+        self.resources().metas().descriptions()
+    }
+
+    /// Gives the conflicts of the `error` the descriptions of the identifiers that they involve such that they are
+    /// readable when formatted.
+    pub fn describe(&mut self, error: Error) -> Error {
+        describe(error, self.descriptions())
+    }
+}
+
+fn describe(error: Error, descriptions: &Descriptions) -> Error {
+    match error {
+        Error::All(errors) => Error::All(
+            errors
+                .into_iter()
+                .map(|error| describe(error, descriptions))
+                .collect(),
+        ),
+        Error::Conflict {
+            systems,
+            errors,
+            descriptions: mut described,
+        } => {
+            for error in errors.iter() {
+                if let Some(Key::At(identifier)) = error.key() {
+                    if let Some(description) = descriptions.description(identifier) {
+                        described.insert(identifier, description);
+                    }
+                }
+            }
+            Error::Conflict {
+                systems,
+                errors,
+                descriptions: described,
+            }
+        }
+        error => error,
+    }
+}
+
+/// Formats a conflict with the described name of its key (if any).
+pub(crate) struct Described<'a>(pub &'a depend::Error, pub &'a BTreeMap<usize, String>);
+
+impl Display for Described<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Described(error, descriptions) = *self;
+        match error.key() {
+            Some(Key::At(identifier)) => match descriptions.get(&identifier) {
+                Some(description) => error.format(f, description),
+                None => Display::fmt(error, f),
+            },
+            _ => Display::fmt(error, f),
+        }
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Key::At(identifier) => write!(f, "identifier #{}", identifier),
+            Key::Type(_, name) => write!(f, "type `{}`", short_name(name)),
+        }
+    }
+}
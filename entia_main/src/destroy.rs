@@ -163,7 +163,7 @@ unsafe impl Resolve for Inner {
             }
         }
 
-        if self.set.len() > 0 {
+        if !self.set.is_empty() {
             self.entities.release(self.set.drain());
         }
 
@@ -1,12 +1,13 @@
 use entia_core::FullIterator;
 
-use crate::{entity::Entity, resource::Resource};
+use crate::{entity::Entity, Resource};
 use std::{
     iter::FusedIterator,
     mem::replace,
     sync::atomic::{AtomicI64, AtomicU64, Ordering},
 };
 
+#[derive(Resource)]
 pub struct Entities {
     free: (Vec<Entity>, AtomicI64),
     data: (Vec<Datum>, AtomicU64),
@@ -17,8 +18,6 @@ pub struct Entities {
 pub struct Children<'a>(u32, u32, u32, &'a Entities);
 pub struct Siblings<'a>(u32, Children<'a>);
 
-impl Resource for Entities {}
-
 impl Default for Entities {
     fn default() -> Self {
         Self::with_capacity(32)
@@ -105,7 +104,7 @@ impl Entities {
     }
 
     pub(crate) fn reserve(&self, entities: &mut [Entity]) -> usize {
-        if entities.len() == 0 {
+        if entities.is_empty() {
             return 0;
         }
 
@@ -299,7 +298,7 @@ impl Entities {
         Some(parent.entity(datum.parent))
     }
 
-    pub fn children(&self, entity: Entity) -> Children {
+    pub fn children(&self, entity: Entity) -> Children<'_> {
         let index = self
             .get_datum(entity)
             .map_or((u32::MAX, u32::MAX, 0), |datum| {
@@ -308,7 +307,7 @@ impl Entities {
         Children(index.0, index.1, index.2, self)
     }
 
-    pub fn siblings(&self, entity: Entity) -> Siblings {
+    pub fn siblings(&self, entity: Entity) -> Siblings<'_> {
         self.parent(entity).map_or(
             Siblings(u32::MAX, Children(u32::MAX, u32::MAX, 0, self)),
             |parent| Siblings(entity.index(), self.children(parent)),
@@ -336,8 +335,14 @@ impl Entities {
         self.try_ascend(
             entity,
             (),
-            |entity, _| Ok::<(), ()>(up(entity)),
-            |entity, _| Ok::<(), ()>(down(entity)),
+            |entity, _| {
+                up(entity);
+                Ok::<(), ()>(())
+            },
+            |entity, _| {
+                down(entity);
+                Ok::<(), ()>(())
+            },
         )
         .unwrap_or(())
     }
@@ -381,8 +386,14 @@ impl Entities {
         self.try_descend(
             entity,
             (),
-            |entity, _| Ok::<(), ()>(down(entity)),
-            |entity, _| Ok::<(), ()>(up(entity)),
+            |entity, _| {
+                down(entity);
+                Ok::<(), ()>(())
+            },
+            |entity, _| {
+                up(entity);
+                Ok::<(), ()>(())
+            },
         )
         .unwrap_or(())
     }
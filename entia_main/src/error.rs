@@ -1,5 +1,10 @@
-use crate::depend;
-use std::{any::TypeId, result};
+use crate::{depend, describe::Described};
+use std::{
+    any::TypeId,
+    collections::BTreeMap,
+    fmt::{self, Debug, Display},
+    result,
+};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -11,6 +16,12 @@ pub enum Error {
     WrongState,
     WrongInput,
     MissingSystem,
+    MissingLabel {
+        label: String,
+    },
+    MissingStage {
+        name: String,
+    },
     MissingStore {
         identifier: TypeId,
         segment: usize,
@@ -25,6 +36,32 @@ pub enum Error {
     MissingClone {
         name: &'static str,
     },
+    MissingDeserialize {
+        name: &'static str,
+    },
+    MissingName {
+        name: String,
+    },
+    MissingPrefab {
+        name: String,
+    },
+    /// An override refers to a component that the prefab does not have.
+    MissingOverride {
+        name: &'static str,
+    },
+    FailedToDeserialize {
+        name: &'static str,
+    },
+    /// The serialized data of the component holds a field that it does not have.
+    UnknownField {
+        name: &'static str,
+    },
+    InvalidPrefab,
+    /// A prefab is combined with templates that add components to its entity, which only the prefab may choose.
+    PrefabMustOwnEntity,
+    /// A prefab was loaded after the `Create` that instantiates it was initialized such that its segments were not
+    /// declared.
+    UndeclaredPrefab,
     SegmentIndexOutOfRange {
         index: usize,
         segment: usize,
@@ -45,7 +82,28 @@ pub enum Error {
     },
     FailedToSchedule,
     FailedToRun,
+    FailedToSerialize,
+    OrderCycle {
+        systems: Vec<String>,
+    },
+    /// The `system` is ordered relative to the `label` of systems that belong to a stage which runs on the wrong side of
+    /// its own.
+    StageOrder {
+        system: String,
+        label: String,
+    },
+    /// The `system` has labels that constraints may refer to, which only a `Scheduler` can resolve.
+    ConstrainedSystem {
+        system: String,
+    },
     Depend(depend::Error),
+    /// Dependency conflicts along with the names of the systems involved in them. The descriptions of the identifiers
+    /// involved in them are given by `World::describe`.
+    Conflict {
+        systems: Vec<String>,
+        errors: Vec<depend::Error>,
+        descriptions: BTreeMap<usize, String>,
+    },
     All(Vec<Error>),
     UnstableWorldVersion,
 }
@@ -75,6 +133,40 @@ impl Error {
         }
     }
 
+    /// Gathers the dependency conflicts of `self` into a `Conflict` that names the `systems` involved in them. Other
+    /// errors are kept as is.
+    pub fn conflict(self, systems: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut conflicts = Vec::new();
+        let mut others = Vec::new();
+        match self.flatten(true) {
+            Some(Error::All(errors)) => {
+                for error in errors {
+                    match error {
+                        Error::Depend(error) => conflicts.push(error),
+                        error => others.push(error),
+                    }
+                }
+            }
+            Some(Error::Depend(error)) => conflicts.push(error),
+            Some(error) => others.push(error),
+            None => {}
+        }
+
+        if !conflicts.is_empty() {
+            others.insert(
+                0,
+                Error::Conflict {
+                    systems: systems.into_iter().map(Into::into).collect(),
+                    errors: conflicts,
+                    descriptions: BTreeMap::new(),
+                },
+            );
+        }
+        Error::All(others)
+            .flatten(false)
+            .unwrap_or(Error::All(Vec::new()))
+    }
+
     pub fn flatten(self, recursive: bool) -> Option<Self> {
         fn descend(error: Error, errors: &mut Vec<Error>, recursive: bool) {
             match error {
@@ -94,7 +186,7 @@ impl Error {
         let mut errors = Vec::new();
         descend(self, &mut errors, recursive);
 
-        if errors.len() == 0 {
+        if errors.is_empty() {
             None
         } else if errors.len() == 1 {
             Some(errors.into_iter().next().unwrap())
@@ -104,28 +196,42 @@ impl Error {
     }
 }
 
-error!(Error);
+impl std::error::Error for Error {}
 
-macro_rules! error {
-    ($t:ty) => {
-        impl std::error::Error for $t {}
-
-        impl std::fmt::Display for $t {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                <Self as std::fmt::Debug>::fmt(self, f)
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Depend(error) => Display::fmt(error, f),
+            Error::Conflict {
+                systems,
+                errors,
+                descriptions,
+            } => {
+                match systems.as_slice() {
+                    [system] => write!(f, "system `{}` has conflicting dependencies:", system)?,
+                    systems => {
+                        let systems: Vec<_> = systems
+                            .iter()
+                            .map(|system| format!("`{}`", system))
+                            .collect();
+                        write!(f, "systems {} conflict:", systems.join(" and "))?
+                    }
+                }
+                for error in errors {
+                    write!(f, "\n    - {}", Described(error, descriptions))?;
+                }
+                Ok(())
             }
-        }
-    };
-    ($t:ty, $e:expr) => {
-        $crate::error::error!($t);
-
-        impl Into<$crate::error::Error> for $t {
-            #[inline]
-            fn into(self) -> $crate::error::Error {
-                $e(self)
+            Error::All(errors) => {
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+                    Display::fmt(error, f)?;
+                }
+                Ok(())
             }
+            error => Debug::fmt(error, f),
         }
-    };
+    }
 }
-
-pub(crate) use error;
@@ -115,10 +115,10 @@ impl<'a> Family<'a> {
     }
 }
 
-impl Into<Entity> for Family<'_> {
+impl From<Family<'_>> for Entity {
     #[inline]
-    fn into(self) -> Entity {
-        self.entity()
+    fn from(family: Family<'_>) -> Self {
+        family.entity()
     }
 }
 
@@ -159,6 +159,23 @@ impl Item for Family<'_> {
 
 pub struct FamilyChunk<'a>(&'a [Entity], &'a Entities);
 
+impl<'a> FamilyChunk<'a> {
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Family<'a>> {
+        Some(Family::new(*self.0.get(index)?, self.1))
+    }
+}
+
 impl<'a> At<'a> for State {
     type State = (<entity::State as At<'a>>::State, &'a Entities);
     type Ref = Family<'a>;
@@ -403,10 +420,10 @@ pub mod template {
         }
     }
 
-    impl Into<Entity> for Family<'_> {
+    impl From<Family<'_>> for Entity {
         #[inline]
-        fn into(self) -> Entity {
-            self.entity()
+        fn from(family: Family<'_>) -> Self {
+            family.entity()
         }
     }
 
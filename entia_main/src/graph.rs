@@ -0,0 +1,250 @@
+use crate::{
+    depend::{self, Dependency, Key, Order, Scope},
+    error::{self, Error},
+};
+use entia_core::utility::short_name;
+use entia_serialize::{
+    json::serialize::JsonSerializer,
+    serialize::Serialize,
+    serializer::{Map, Serializer},
+};
+use std::{collections::BTreeMap, fmt::Write};
+
+/// A snapshot of the runs that a `Runner` computed for its systems along with the blockers that order them.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub index: usize,
+    pub system: String,
+    pub stage: String,
+    pub exclusive: bool,
+    pub dependencies: Vec<Dependency>,
+    /// Runs that must be done before this run may begin.
+    pub strong: Vec<Edge>,
+    /// Runs that may not execute at the same time as this run, in any order.
+    pub weak: Vec<Edge>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub blocker: usize,
+    /// The conflicts between the dependencies of the runs that caused this edge.
+    pub errors: Vec<depend::Error>,
+}
+
+impl Graph {
+    /// Formats the graph in the Graphviz DOT language. Strong edges go from the blocker to the blocked run and weak
+    /// edges are dashed.
+    pub fn dot(&self) -> String {
+        let mut dot = String::from("digraph runner {\n    node [shape=box];\n");
+        for node in self.nodes.iter() {
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}\\n{}{}\"];",
+                node.index,
+                escape(&node.system),
+                escape(&node.stage),
+                if node.exclusive { " (exclusive)" } else { "" }
+            );
+        }
+        for node in self.nodes.iter() {
+            for edge in node.strong.iter() {
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {} [label=\"{}\"];",
+                    edge.blocker,
+                    node.index,
+                    escape(&label(&edge.errors))
+                );
+            }
+            // Weak edges are symmetric so only one of them is written.
+            for edge in node.weak.iter().filter(|edge| edge.blocker < node.index) {
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {} [style=dashed, dir=none, label=\"{}\"];",
+                    edge.blocker,
+                    node.index,
+                    escape(&label(&edge.errors))
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn json(&self) -> error::Result<String> {
+        JsonSerializer::serialize(self).map_err(|_| Error::FailedToSerialize)
+    }
+
+    /// Reports the conflicts that caused the strong edges, naming the blocker and the blocked systems. `World::describe`
+    /// names the data involved in them.
+    pub fn conflicts(&self) -> Vec<Error> {
+        let mut conflicts = Vec::new();
+        for node in self.nodes.iter() {
+            for edge in node.strong.iter() {
+                let blocker = &self.nodes[edge.blocker];
+                conflicts.push(Error::Conflict {
+                    systems: vec![blocker.system.clone(), node.system.clone()],
+                    errors: edge.errors.clone(),
+                    descriptions: BTreeMap::new(),
+                });
+            }
+        }
+        conflicts
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn label(errors: &[depend::Error]) -> String {
+    errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>()
+        .join("\\n")
+}
+
+pub(crate) fn errors(error: Error) -> Vec<depend::Error> {
+    match error {
+        Error::Depend(error) => vec![error],
+        Error::All(errors) => errors.into_iter().flat_map(self::errors).collect(),
+        _ => vec![],
+    }
+}
+
+/// Weak blockers do not keep the error that caused them since their conflicts are relaxed, so it is rebuilt here.
+pub(crate) fn relaxed(left: &[Dependency], right: &[Dependency]) -> Vec<depend::Error> {
+    let mut errors = Vec::new();
+    for left in left.iter() {
+        for right in right.iter() {
+            match (left, right) {
+                (Dependency::Write(left, _), Dependency::Write(right, _)) if left == right => {
+                    errors.push(depend::Error::WriteWriteConflict(
+                        *left,
+                        Scope::Outer,
+                        Order::Relax,
+                    ))
+                }
+                (Dependency::Read(left, _), Dependency::Write(right, _))
+                | (Dependency::Write(left, _), Dependency::Read(right, _))
+                    if left == right =>
+                {
+                    errors.push(depend::Error::ReadWriteConflict(
+                        *left,
+                        Scope::Outer,
+                        Order::Relax,
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+    errors
+}
+
+impl Serialize for Graph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        serializer.map()?.pair("nodes", &self.nodes)?.end()
+    }
+}
+
+impl Serialize for Node {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        serializer
+            .map()?
+            .pair("index", self.index)?
+            .pair("system", &self.system)?
+            .pair("stage", &self.stage)?
+            .pair("exclusive", self.exclusive)?
+            .pair("dependencies", &self.dependencies)?
+            .pair("strong", &self.strong)?
+            .pair("weak", &self.weak)?
+            .end()
+    }
+}
+
+impl Serialize for Edge {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        serializer
+            .map()?
+            .pair("blocker", self.blocker)?
+            .pair("errors", &self.errors)?
+            .end()
+    }
+}
+
+impl Serialize for Dependency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        match self {
+            Dependency::Unknown => serializer.string("Unknown"),
+            Dependency::Thread => serializer.string("Thread"),
+            Dependency::Read(key, order) => serializer
+                .map()?
+                .pair("read", key)?
+                .pair("order", order)?
+                .end(),
+            Dependency::Write(key, order) => serializer
+                .map()?
+                .pair("write", key)?
+                .pair("order", order)?
+                .end(),
+        }
+    }
+}
+
+impl Serialize for depend::Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        let (kind, key, scope, order) = match *self {
+            depend::Error::UnknownConflict(scope) => ("UnknownConflict", None, scope, None),
+            depend::Error::ReadWriteConflict(key, scope, order) => {
+                ("ReadWriteConflict", Some(key), scope, Some(order))
+            }
+            depend::Error::WriteWriteConflict(key, scope, order) => {
+                ("WriteWriteConflict", Some(key), scope, Some(order))
+            }
+        };
+        let map = serializer.map()?.pair("conflict", kind)?;
+        let map = match key {
+            Some(key) => map.pair("key", key)?,
+            None => map,
+        };
+        let map = map.pair("scope", scope)?;
+        match order {
+            Some(order) => map.pair("order", order)?.end(),
+            None => map.end(),
+        }
+    }
+}
+
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        match self {
+            Key::At(identifier) => serializer.usize(*identifier),
+            Key::Type(_, name) => serializer.string(&short_name(name)),
+        }
+    }
+}
+
+impl Serialize for Order {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        serializer.string(match self {
+            Order::Relax => "Relax",
+            Order::Strict => "Strict",
+        })
+    }
+}
+
+impl Serialize for Scope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        serializer.string(match self {
+            Scope::Inner => "Inner",
+            Scope::Outer => "Outer",
+        })
+    }
+}
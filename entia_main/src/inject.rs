@@ -1,7 +1,7 @@
 use crate::{
     depend::{Conflict, Dependency},
     error::{Error, Result},
-    identify,
+    identify, message,
     run::{as_mut, Run},
     tuples_with,
     world::World,
@@ -91,9 +91,11 @@ impl<'a, T, A: Adapt<T>> Schedule<'a, T, A> {
     where
         'a: 'b,
     {
-        &mut self.context
+        self.context
     }
 
+    /// Adds a run that prepares the state before the run of the system. When it fails, the runs that it blocks (which
+    /// should include the run of the system) are skipped for the frame regardless of the `Policy` of the system.
     pub fn pre<
         F: FnMut(&mut T) -> Result + Send + Sync + 'static,
         I: IntoIterator<Item = Dependency>,
@@ -102,7 +104,7 @@ impl<'a, T, A: Adapt<T>> Schedule<'a, T, A> {
         run: F,
         dependencies: I,
     ) {
-        self.pre.push(self.run(run, dependencies));
+        self.pre.push(self.run(run, dependencies).prepare());
     }
 
     pub fn post<
@@ -360,7 +362,9 @@ impl<I: Inject> Injector<I> {
                 }
 
                 for run in self.pre.iter().chain(self.post.iter()) {
-                    conflict.detect_inner(run.dependencies(), true)?;
+                    conflict
+                        .detect_inner(run.dependencies(), true)
+                        .map_err(|error| world.describe(error.conflict([self.name.as_str()])))?;
                 }
             } else {
                 break;
@@ -372,7 +376,9 @@ impl<I: Inject> Injector<I> {
         }
 
         self.dependencies = I::depend(&self.state);
-        conflict.detect_inner(&self.dependencies, true)?;
+        conflict
+            .detect_inner(&self.dependencies, true)
+            .map_err(|error| world.describe(error.conflict([self.name.as_str()])))?;
 
         // Only commit the new version if scheduling and dependency analysis succeed.
         self.version = version;
@@ -385,6 +391,7 @@ impl<I: Inject> Injector<I> {
         run: R,
     ) -> Result<T> {
         self.update(world)?;
+        message::deliver(world);
         let state = as_mut(&mut self.state);
         for run in self.pre.iter_mut() {
             run.run(state)?;
@@ -466,6 +473,7 @@ macro_rules! inject {
             }
         }
 
+        #[allow(clippy::unused_unit)]
         impl<'a, $($t: Get<'a>,)*> Get<'a> for ($($t,)*) {
             type Item = ($($t::Item,)*);
 
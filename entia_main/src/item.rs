@@ -105,6 +105,7 @@ macro_rules! item {
             }
         }
 
+        #[allow(clippy::unused_unit)]
         impl<'a, I: Clone, $($t: At<'a, I>,)*> At<'a, I> for ($($t,)*) {
             type State = ($($t::State,)*);
             type Ref = ($($t::Ref,)*);
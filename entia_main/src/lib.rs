@@ -1,16 +1,16 @@
-#![feature(type_alias_impl_trait)]
-#![feature(generic_associated_types)]
-#![feature(never_type)]
-#![feature(strict_provenance_atomic_ptr)]
-#![feature(generators)]
-#![feature(generator_trait)]
-#![feature(iter_from_generator)]
+#![feature(impl_trait_in_assoc_type)]
+// Unsafe functions describe their contract in a 'SAFETY:' comment.
+#![allow(clippy::missing_safety_doc, clippy::type_complexity)]
+
+// Allows the derive macros, which refer to 'entia', to be used within this crate.
+extern crate self as entia;
 
 pub mod add;
 pub mod component;
 pub mod create;
 pub mod defer;
 pub mod depend;
+pub mod describe;
 pub mod destroy;
 pub mod entities;
 pub mod entity;
@@ -18,20 +18,27 @@ pub mod error;
 pub mod families;
 pub mod family;
 pub mod filter;
+pub mod graph;
 pub mod inject;
 pub mod item;
+pub mod local;
 pub mod message;
 pub mod meta;
 pub mod output;
+pub mod pinned;
+pub mod prefab;
+pub mod profile;
 pub mod query;
 pub mod resource;
 pub mod resources;
 pub mod run;
 pub mod schedule;
 pub mod segment;
+pub mod stage;
 pub mod store;
 pub mod system;
 pub mod template;
+pub mod transfer;
 pub mod world;
 
 /*
@@ -144,12 +151,15 @@ pub use crate::{
     family::Family,
     filter::{Filter, Has, Not},
     inject::{Inject, Injector},
-    message::{emit::Emit, receive::Receive, Message},
+    local::Local,
+    message::{emit::Emit, receive::Receive, target::Target, Message},
+    pinned::Pinned,
+    prefab::Prefab,
     query::Query,
     resource::Resource,
     run::Runner,
     schedule::Scheduler,
-    system::{IntoSystem, System},
+    system::{IntoPipe, IntoSystem, System},
     template::{Add, LeafTemplate, Spawn, SpawnTemplate, StaticTemplate, Template, With},
     world::World,
 };
@@ -214,6 +224,18 @@ pub mod boba {
         }
     }
 
+    impl<T> Default for BobaSlot<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T, const N: usize> Default for BobaVec<T, N> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     impl<T, const N: usize> BobaVec<T, N> {
         #[inline]
         pub fn new() -> Self {
@@ -229,14 +251,17 @@ pub mod boba {
             self.count.load(Ordering::Acquire)
         }
 
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
         pub fn resolve(&mut self) {
             let count = *self.count.get_mut();
             let over = self.over.get_mut();
-            let mut offset = 1;
-            for value in over.drain(..) {
-                let slot = &mut self.slots[(count + offset) % N];
+            for (offset, value) in over.drain(..).enumerate() {
+                let slot = &mut self.slots[(count + offset + 1) % N];
                 slot.items.get_mut().push(value);
-                offset += 1;
             }
         }
 
@@ -280,6 +305,8 @@ pub mod boba {
     }
 }
 
+// Sketches of alternative designs; they are not used yet.
+#[allow(dead_code, unused_imports)]
 pub mod pouahl {
     use super::*;
     use crate::store::Store;
@@ -380,6 +407,7 @@ pub mod pouahl {
     */
 }
 
+#[allow(dead_code, unused_imports)]
 pub mod poulah {
     use super::*;
     use crate::{error::Result, item::Item, segment::Segment};
@@ -0,0 +1,52 @@
+use crate::{
+    depend::Dependency,
+    error::Result,
+    inject::{Adapt, Context, Get, Inject},
+};
+use std::ops::{Deref, DerefMut};
+
+/// Persistent state that is private to one system instance. Since no other system can observe it, it declares no
+/// dependencies and never causes conflicts.
+pub struct Local<'a, T>(&'a mut T);
+pub struct State<T>(T);
+
+impl<T> Deref for Local<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<T> DerefMut for Local<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+unsafe impl<T: Default + Send + Sync + 'static> Inject for Local<'_, T> {
+    type Input = Option<T>;
+    type State = State<T>;
+
+    fn initialize<A: Adapt<Self::State>>(
+        input: Self::Input,
+        _: Context<Self::State, A>,
+    ) -> Result<Self::State> {
+        Ok(State(input.unwrap_or_default()))
+    }
+
+    fn depend(_: &Self::State) -> Vec<Dependency> {
+        vec![]
+    }
+}
+
+impl<'a, T: 'static> Get<'a> for State<T> {
+    type Item = Local<'a, T>;
+
+    #[inline]
+    unsafe fn get(&'a mut self) -> Self::Item {
+        Local(&mut self.0)
+    }
+}
@@ -1,4 +1,7 @@
-use entia::{entities::Entities, message::keep, segment::Segments, system::Barrier, *};
+// A scratch binary to exercise the library; most of its declarations are kept around to be toggled in and out.
+#![allow(dead_code, unused_variables)]
+
+use entia::{entities::Entities, segment::Segments, *};
 use entia_main as entia;
 
 fn main() {
@@ -21,9 +24,9 @@ fn main() {
     #[derive(Component)]
     struct Dead;
 
-    impl Into<Entity> for OnDeath {
-        fn into(self) -> Entity {
-            self.0
+    impl From<OnDeath> for Entity {
+        fn from(message: OnDeath) -> Self {
+            message.0
         }
     }
 
@@ -212,9 +215,10 @@ fn main() {
 
         let mut boba = 0;
         inject
-            .run(&mut world, move |(segments, _entities)| {
+            .run(&mut world, |(segments, _entities)| {
                 boba += segments.len();
             })
             .unwrap();
+        println!("{}", boba);
     }
 }
@@ -1,36 +1,265 @@
-use self::keep::{IntoKeep, Keep};
+use self::{
+    emit::Emit,
+    keep::{IntoKeep, Keep},
+};
 use crate::{
     depend::Dependency,
-    error::Result,
+    describe::Descriptions,
+    error::{Error, Result},
     identify,
     inject::{Adapt, Context, Get, Inject},
     meta::Meta,
-    resource::Resource,
     resource::{Read, Write},
+    run::Frame,
+    world::World,
+    Resource,
+};
+use entia_core::utility::short_type_name;
+use entia_serialize::{
+    node::{serialize::NodeSerializer, Node},
+    serialize::Serialize,
+    serializer::Serializer,
+};
+use parking_lot::Mutex;
+use std::{
+    any::TypeId,
+    collections::{HashMap, VecDeque},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::take,
+    ops::Range,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Sender},
+        Arc, Weak,
+    },
 };
-use std::{collections::VecDeque, iter::FusedIterator, marker::PhantomData};
 
-pub trait Message: Clone + Send + Sync + 'static {
+/// The number of slots of the timer wheel of delayed messages.
+const SLOTS: usize = 64;
+
+/// Messages are stored once in a buffer that is shared by all the receivers of their type and are read by reference,
+/// so they do not need to implement `Clone`.
+pub trait Message: Sized + Send + Sync + 'static {
     fn meta() -> Meta {
         crate::meta!(Self)
     }
 }
 
-struct Queue<T> {
+/// The position of a receiver in the shared buffer. It is owned by the state of the receiver such that dropping the
+/// receiver releases it.
+struct Cursor {
     identifier: usize,
     keep: Keep,
-    items: VecDeque<T>,
+    /// The absolute index of the next message to read.
+    position: AtomicUsize,
 }
 
+#[derive(Resource)]
 struct Inner<T> {
-    pub queues: Vec<Queue<T>>,
+    /// The absolute index of the first message of `messages`.
+    offset: usize,
+    messages: VecDeque<T>,
+    /// The cursors of the receivers by identifier. Cursors of dropped receivers are ignored until they are released.
+    cursors: HashMap<usize, Weak<Cursor>>,
+    /// The last frame for which delayed messages were delivered.
+    frame: usize,
+    /// Delayed messages stored in the slot of the frame in which they are due.
+    wheel: Vec<Vec<Delayed<T>>>,
+    /// Whether the messages of live emitters are ignored in favor of replayed ones.
+    replay: bool,
+    recorder: Option<Recorder<T>>,
+    /// Messages that were sent from outside of the world through a `MessageSender`.
+    inbox: Option<(Sender<T>, Mutex<mpsc::Receiver<T>>)>,
+    /// Forwards the emitted messages to an `Outbox`. Returns `false` when the `Outbox` has been dropped.
+    outboxes: Vec<Box<dyn Fn(&T) -> bool + Send + Sync>>,
+}
+
+/// Delivers the delayed messages and the messages of the inbox of each message type once per frame (see `deliver`).
+#[derive(Resource, Default)]
+struct Deliveries(HashMap<TypeId, fn(&mut World, usize)>);
+
+struct Delayed<T> {
+    frame: usize,
+    /// Whether the message comes from `World::replay` rather than from a live emitter.
+    replayed: bool,
+    message: T,
 }
 
-impl<T: Send + Sync + 'static> Resource for Inner<T> {}
+struct Recorder<T> {
+    serialize: fn(&T) -> Option<Node>,
+    messages: Vec<(usize, Node)>,
+    /// Whether a message failed to serialize, in which case the recording is incomplete.
+    failed: bool,
+}
 
-impl<T: Send + Sync + 'static> Default for Inner<T> {
+impl<T> Default for Inner<T> {
     fn default() -> Self {
-        Self { queues: Vec::new() }
+        Self {
+            offset: 0,
+            messages: VecDeque::new(),
+            cursors: HashMap::new(),
+            frame: 0,
+            wheel: Vec::new(),
+            replay: false,
+            recorder: None,
+            inbox: None,
+            outboxes: Vec::new(),
+        }
+    }
+}
+
+impl<T> Inner<T> {
+    #[inline]
+    fn end(&self) -> usize {
+        self.offset + self.messages.len()
+    }
+
+    fn emit(&mut self, frame: usize, messages: impl IntoIterator<Item = T>) {
+        // Messages that no receiver can read are not buffered but they are still recorded and forwarded.
+        let receive = self
+            .cursors
+            .values()
+            .any(|cursor| cursor.strong_count() > 0);
+        if receive {
+            self.trim();
+        }
+        for message in messages {
+            if let Some(recorder) = &mut self.recorder {
+                match (recorder.serialize)(&message) {
+                    Some(node) => recorder.messages.push((frame, node)),
+                    None => recorder.failed = true,
+                }
+            }
+            self.outboxes.retain(|outbox| outbox(&message));
+            if receive {
+                self.messages.push_back(message);
+            }
+        }
+    }
+
+    fn delay(&mut self, frame: usize, replayed: bool, message: T) {
+        if frame <= self.frame {
+            // The slot of a past frame would only be visited once the wheel has turned.
+            self.emit(self.frame, Some(message));
+            return;
+        } else if self.wheel.is_empty() {
+            self.wheel.resize_with(SLOTS, Vec::new);
+        }
+        self.wheel[frame % SLOTS].push(Delayed {
+            frame,
+            replayed,
+            message,
+        });
+    }
+
+    /// Emits the messages of the inbox and the delayed messages that are due by the `frame`.
+    fn deliver(&mut self, frame: usize) {
+        // The messages of the inbox remain in it during a replay such that they are emitted once it is over.
+        if let Some((_, inbox)) = self.inbox.as_mut().filter(|_| !self.replay) {
+            let messages: Vec<_> = inbox.get_mut().try_iter().collect();
+            self.emit(frame, messages);
+        }
+
+        if frame <= self.frame {
+            return;
+        }
+
+        let mut due = Vec::new();
+        // When more frames than slots have passed, each slot is visited once.
+        for current in (self.frame + 1..=frame).take(self.wheel.len()) {
+            let slot = &mut self.wheel[current % SLOTS];
+            let (ready, pending) = take(slot)
+                .into_iter()
+                .partition::<Vec<_>, _>(|delayed| delayed.frame <= frame);
+            *slot = pending;
+            due.extend(ready);
+        }
+        self.frame = frame;
+        due.sort_by_key(|delayed| delayed.frame);
+        // Live messages that were delayed before a replay are ignored like the ones of live emitters.
+        let replay = self.replay;
+        let due = due
+            .into_iter()
+            .filter(|delayed| delayed.replayed || !replay);
+        self.emit(frame, due.map(|delayed| delayed.message));
+    }
+
+    /// Removes the messages that all the cursors have passed.
+    fn trim(&mut self) {
+        let end = self.end();
+        let start = self
+            .cursors
+            .values()
+            .filter_map(Weak::upgrade)
+            .map(|cursor| cursor.window(end).start)
+            .min()
+            .unwrap_or(end);
+        let count = start.saturating_sub(self.offset).min(self.messages.len());
+        self.messages.drain(..count);
+        self.offset += count;
+    }
+
+    /// Removes the cursors of the receivers that were dropped along with their description.
+    fn release(&mut self, descriptions: &Descriptions) {
+        self.cursors.retain(|&identifier, cursor| {
+            let alive = cursor.strong_count() > 0;
+            if !alive {
+                descriptions.forget(identifier);
+            }
+            alive
+        });
+    }
+}
+
+fn inner<M: Message>(world: &mut World) -> &mut Inner<M> {
+    let resources = world.resources();
+    if !resources.has::<Deliveries>() {
+        resources.set(Deliveries::default());
+    }
+    resources
+        .get_mut::<Deliveries>()
+        .expect("Deliveries resource must have been added.")
+        .0
+        .entry(TypeId::of::<M>())
+        .or_insert(|world, frame| {
+            if let Some(inner) = world.resources().get_mut::<Inner<M>>() {
+                inner.deliver(frame);
+            }
+        });
+    if !resources.has::<Inner<M>>() {
+        resources.set(Inner::<M>::default());
+    }
+    resources
+        .get_mut()
+        .expect("Message resource must have been added.")
+}
+
+/// Emits the messages of the inbox and the delayed messages that are due in the current frame for every message type.
+/// It requires exclusive access to the world such that receivers of the same type never wait on each other.
+pub(crate) fn deliver(world: &mut World) {
+    let frame = world
+        .resources()
+        .get::<Frame>()
+        .map_or(0, |frame| frame.index());
+    let deliveries: Vec<_> = match world.resources().get::<Deliveries>() {
+        Some(deliveries) => deliveries.0.values().copied().collect(),
+        None => return,
+    };
+    for deliver in deliveries {
+        deliver(world, frame);
+    }
+}
+
+impl Cursor {
+    /// The range of messages that are visible to the receiver given the `end` of the buffer.
+    fn window(&self, end: usize) -> Range<usize> {
+        let position = self.position.load(Ordering::Relaxed);
+        match self.keep {
+            Keep::All => position..end,
+            Keep::Last(count) => position.max(end.saturating_sub(count))..end,
+            Keep::First(count) => position..end.min(position + count),
+        }
     }
 }
 
@@ -72,76 +301,42 @@ pub mod keep {
 pub mod emit {
     use super::*;
 
-    pub struct Emit<'a, M>(&'a mut [Queue<M>]);
-    pub struct State<T>(Write<Inner<T>>);
+    pub struct Emit<'a, M> {
+        inner: &'a mut Inner<M>,
+        frame: usize,
+    }
 
-    impl<T: Clone> Emit<'_, T> {
-        pub fn all(&mut self, messages: impl IntoIterator<Item = T>) {
-            fn enqueue<I: IntoIterator>(queue: &mut Queue<I::Item>, messages: I) {
-                match queue.keep {
-                    Keep::All => queue.items.extend(messages),
-                    Keep::Last(count) => {
-                        for message in messages {
-                            if queue.items.len() >= count {
-                                queue.items.pop_front();
-                            }
-                            queue.items.push_back(message);
-                        }
-                    }
-                    Keep::First(count) => {
-                        let take = count.saturating_sub(queue.items.len());
-                        queue.items.extend(messages.into_iter().take(take));
-                    }
-                }
-            }
+    pub struct State<T> {
+        inner: Write<Inner<T>>,
+        frame: Read<Frame>,
+    }
 
-            match self.0.split_first_mut() {
-                Some((head, [])) => enqueue(head, messages),
-                Some((head, rest)) => {
-                    // Use 'head' as a buffer for the 'rest' queues.
-                    let start = head.items.len();
-                    head.items.extend(messages);
-
-                    for queue in rest {
-                        enqueue(queue, head.items.range(start..).cloned());
-                    }
-
-                    // Remove overflow from 'head'.
-                    match head.keep {
-                        Keep::First(count) => head.items.truncate(count),
-                        Keep::Last(count) => {
-                            head.items.drain(..head.items.len().saturating_sub(count));
-                        }
-                        _ => {}
-                    }
-                }
-                None => {}
+    impl<T> Emit<'_, T> {
+        #[inline]
+        pub fn all(&mut self, messages: impl IntoIterator<Item = T>) {
+            if !self.inner.replay {
+                self.inner.emit(self.frame, messages);
             }
         }
 
+        #[inline]
         pub fn one(&mut self, message: T) {
-            fn enqueue<T>(queue: &mut Queue<T>, message: T) {
-                match queue.keep {
-                    Keep::All => queue.items.push_back(message),
-                    Keep::Last(count) => {
-                        if queue.items.len() >= count {
-                            queue.items.pop_front();
-                        }
-                        queue.items.push_back(message);
-                    }
-                    Keep::First(count) => {
-                        if queue.items.len() < count {
-                            queue.items.push_front(message);
-                        }
-                    }
-                }
-            }
+            self.all(Some(message));
+        }
 
-            if let Some((head, rest)) = self.0.split_first_mut() {
-                for queue in rest {
-                    enqueue(queue, message.clone());
-                }
-                enqueue(head, message);
+        /// Emits the `message` once `frames` frames have passed after the current one.
+        #[inline]
+        pub fn after(&mut self, frames: usize, message: T) {
+            self.at(self.frame + frames, message);
+        }
+
+        /// Emits the `message` in the `frame` (see `Frame`), or immediately if that frame has already begun. Delayed
+        /// messages are delivered at the beginning of their frame, before any system runs.
+        pub fn at(&mut self, frame: usize, message: T) {
+            if frame <= self.frame {
+                self.one(message);
+            } else if !self.inner.replay {
+                self.inner.delay(frame, false, message);
             }
         }
     }
@@ -154,14 +349,20 @@ pub mod emit {
             _: Self::Input,
             mut context: Context<Self::State, A>,
         ) -> Result<Self::State> {
-            Ok(State(Write::initialize(
-                None,
-                context.map(|State(state)| state),
-            )?))
+            inner::<M>(context.world());
+            let inner = Write::initialize(None, context.map(|state| &mut state.inner))?;
+            let frame = Read::initialize(None, context.map(|state| &mut state.frame))?;
+            context.schedule(|state, mut schedule| {
+                let descriptions = schedule.context().world().descriptions();
+                state.inner.release(descriptions);
+            });
+            Ok(State { inner, frame })
         }
 
-        fn depend(State(state): &Self::State) -> Vec<Dependency> {
-            Write::depend(state)
+        fn depend(State { inner, frame }: &Self::State) -> Vec<Dependency> {
+            let mut dependencies = Write::depend(inner);
+            dependencies.extend(Read::depend(frame));
+            dependencies
         }
     }
 
@@ -170,7 +371,10 @@ pub mod emit {
 
         #[inline]
         unsafe fn get(&'a mut self) -> Self::Item {
-            Emit(&mut self.0.queues)
+            Emit {
+                inner: &mut self.inner,
+                frame: self.frame.index(),
+            }
         }
     }
 }
@@ -180,9 +384,23 @@ pub mod receive {
 
     use super::*;
 
-    pub struct Receive<'a, T, K = keep::All>(&'a mut VecDeque<T>, PhantomData<K>);
+    /// Iterates over the messages that were emitted since the last time the receiver ran, as allowed by its `Keep`
+    /// policy. Messages that are not iterated remain available for the next run.
+    pub struct Receive<'a, T, K = keep::All> {
+        messages: &'a VecDeque<T>,
+        offset: usize,
+        cursor: &'a AtomicUsize,
+        front: usize,
+        back: usize,
+        /// The end of the buffer when the receiver was created.
+        end: usize,
+        /// Whether a message was read from the back, in which case the messages that remain are discarded.
+        back_read: bool,
+        _marker: PhantomData<K>,
+    }
+
     pub struct State<T, K> {
-        queue: usize,
+        cursor: Arc<Cursor>,
         inner: Write<Inner<T>>,
         _marker: PhantomData<fn(K)>,
     }
@@ -190,21 +408,27 @@ pub mod receive {
     impl<T, K> Receive<'_, T, K> {
         #[inline]
         pub fn clear(&mut self) {
-            self.0.clear()
+            self.front = self.back;
         }
     }
 
-    impl<T, K> Iterator for Receive<'_, T, K> {
-        type Item = T;
+    impl<'a, T, K> Iterator for Receive<'a, T, K> {
+        type Item = &'a T;
 
         #[inline]
         fn next(&mut self) -> Option<Self::Item> {
-            self.0.pop_front()
+            if self.front < self.back {
+                self.front += 1;
+                self.messages.get(self.front - 1 - self.offset)
+            } else {
+                None
+            }
         }
 
         #[inline]
         fn nth(&mut self, n: usize) -> Option<Self::Item> {
-            self.0.drain(0..=n).last()
+            self.front = self.back.min(self.front + n);
+            self.next()
         }
 
         #[inline]
@@ -217,24 +441,43 @@ pub mod receive {
     impl<T, K> DoubleEndedIterator for Receive<'_, T, K> {
         #[inline]
         fn next_back(&mut self) -> Option<Self::Item> {
-            self.0.pop_back()
+            if self.front < self.back {
+                self.back -= 1;
+                self.back_read = true;
+                self.messages.get(self.back - self.offset)
+            } else {
+                None
+            }
         }
 
         #[inline]
         fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-            self.0.drain(self.0.len() - n - 1..).last()
+            self.back = self.front.max(self.back.saturating_sub(n));
+            self.next_back()
         }
     }
 
     impl<T, K> ExactSizeIterator for Receive<'_, T, K> {
         #[inline]
         fn len(&self) -> usize {
-            self.0.len()
+            self.back - self.front
         }
     }
 
     impl<T, K> FusedIterator for Receive<'_, T, K> {}
 
+    impl<T, K> Drop for Receive<'_, T, K> {
+        fn drop(&mut self) {
+            // When all the visible messages have been read, the ones that the `Keep` policy excluded are skipped.
+            let position = if self.front == self.back || self.back_read {
+                self.end
+            } else {
+                self.front
+            };
+            self.cursor.store(position, Ordering::Relaxed);
+        }
+    }
+
     unsafe impl<M: Message, K: IntoKeep + 'static> Inject for Receive<'_, M, K> {
         type Input = ();
         type State = State<M, K>;
@@ -243,29 +486,41 @@ pub mod receive {
             _: Self::Input,
             mut context: Context<Self::State, A>,
         ) -> Result<Self::State> {
+            inner::<M>(context.world());
             let mut inner = Write::initialize(None, context.map(|state| &mut state.inner))?;
-            let queue = {
-                let index = inner.queues.len();
-                inner.queues.push(Queue {
-                    identifier: identify(),
-                    keep: K::keep(),
-                    items: VecDeque::new(),
-                });
-                index
-            };
+            let identifier = identify();
+            context.world().descriptions().describe(
+                identifier,
+                format!("message cursor of `{}`", short_type_name::<M>()),
+            );
+            let cursor = Arc::new(Cursor {
+                identifier,
+                keep: K::keep(),
+                position: AtomicUsize::new(inner.end()),
+            });
+            inner.cursors.insert(identifier, Arc::downgrade(&cursor));
+            context.schedule(|state, mut schedule| {
+                let descriptions = schedule.context().world().descriptions();
+                let State { cursor, inner, .. } = state;
+                inner.release(descriptions);
+                // The buffer is replaced along with its resource, in which case the cursor starts at its end.
+                if !inner.cursors.contains_key(&cursor.identifier) {
+                    cursor.position.store(inner.end(), Ordering::Relaxed);
+                    inner
+                        .cursors
+                        .insert(cursor.identifier, Arc::downgrade(cursor));
+                }
+            });
             Ok(State {
-                queue,
+                cursor,
                 inner,
                 _marker: PhantomData,
             })
         }
 
-        fn depend(State { inner, queue, .. }: &Self::State) -> Vec<Dependency> {
+        fn depend(State { inner, cursor, .. }: &Self::State) -> Vec<Dependency> {
             let mut dependencies = Read::depend(&inner.read());
-            dependencies.push(Dependency::write_at(
-                inner.queues[*queue].identifier,
-                Order::Strict,
-            ));
+            dependencies.push(Dependency::write_at(cursor.identifier, Order::Strict));
             dependencies
         }
     }
@@ -275,7 +530,316 @@ pub mod receive {
 
         #[inline]
         unsafe fn get(&'a mut self) -> Self::Item {
-            Receive(&mut self.inner.queues[self.queue].items, PhantomData)
+            // Only the cursor of this receiver is modified, through an atomic, so the buffer is only read.
+            let inner: &'a Inner<T> = &self.inner;
+            let cursor: &'a Cursor = &self.cursor;
+            let end = inner.end();
+            let window = cursor.window(end);
+            Receive {
+                messages: &inner.messages,
+                offset: inner.offset,
+                cursor: &cursor.position,
+                front: window.start,
+                back: window.end,
+                end,
+                back_read: false,
+                _marker: PhantomData,
+            }
+        }
+    }
+}
+
+pub mod target {
+    use super::{receive::Receive, *};
+    use crate::{entity::Entity, item::Item, query::Query};
+    use std::iter::once;
+
+    /// A message that is addressed to an entity. A bubbling message is also delivered to each ancestor of its entity,
+    /// from the nearest to the root, until it is stopped.
+    pub struct Target<M> {
+        entity: Entity,
+        message: M,
+        bubble: bool,
+        /// The deepest ancestor that may still receive the message.
+        depth: AtomicUsize,
+    }
+
+    /// A `Target` message as it is delivered to one of the entities that it reaches.
+    pub struct Delivery<'a, M> {
+        entity: Entity,
+        depth: usize,
+        target: &'a Target<M>,
+    }
+
+    impl<M: Message> Message for Target<M> {}
+
+    impl<M> Target<M> {
+        #[inline]
+        pub const fn new(entity: Entity, message: M) -> Self {
+            Self {
+                entity,
+                message,
+                bubble: false,
+                depth: AtomicUsize::new(0),
+            }
+        }
+
+        #[inline]
+        pub const fn bubble(entity: Entity, message: M) -> Self {
+            Self {
+                entity,
+                message,
+                bubble: true,
+                depth: AtomicUsize::new(usize::MAX),
+            }
+        }
+
+        #[inline]
+        pub const fn entity(&self) -> Entity {
+            self.entity
+        }
+
+        #[inline]
+        pub const fn message(&self) -> &M {
+            &self.message
+        }
+
+        #[inline]
+        pub const fn bubbles(&self) -> bool {
+            self.bubble
+        }
+    }
+
+    impl<'a, M> Delivery<'a, M> {
+        /// The entity that receives the message; an ancestor of the target entity if the message has bubbled.
+        #[inline]
+        pub const fn entity(&self) -> Entity {
+            self.entity
+        }
+
+        #[inline]
+        pub const fn target(&self) -> &'a Target<M> {
+            self.target
+        }
+
+        #[inline]
+        pub const fn message(&self) -> &'a M {
+            &self.target.message
+        }
+
+        /// Prevents the message from reaching the ancestors of the current entity. Receivers that run later still
+        /// receive it up to the current entity, so receivers that stop messages should be ordered relative to the others.
+        #[inline]
+        pub fn stop(&self) {
+            self.target.depth.fetch_min(self.depth, Ordering::Relaxed);
+        }
+    }
+
+    impl<M> Emit<'_, Target<M>> {
+        #[inline]
+        pub fn to(&mut self, entity: impl Into<Entity>, message: M) {
+            self.one(Target::new(entity.into(), message));
+        }
+
+        #[inline]
+        pub fn bubble(&mut self, entity: impl Into<Entity>, message: M) {
+            self.one(Target::bubble(entity.into(), message));
+        }
+    }
+
+    impl<'a, M, K> Receive<'a, Target<M>, K> {
+        /// Delivers the messages to the entities that are matched by the `query`.
+        pub fn on<'q, I: Item + 'static, F: 'static>(
+            self,
+            query: &'q Query<'_, I, F>,
+        ) -> impl Iterator<Item = Delivery<'a, M>> + 'q
+        where
+            'a: 'q,
+            M: 'a,
+            K: 'q,
+        {
+            let entities = query.entities;
+            self.flat_map(move |target| {
+                let ancestors = target
+                    .bubble
+                    .then(|| entities.ancestors(target.entity))
+                    .into_iter()
+                    .flatten();
+                once(target.entity)
+                    .chain(ancestors)
+                    .enumerate()
+                    .take_while(move |&(depth, _)| depth <= target.depth.load(Ordering::Relaxed))
+                    .filter(move |&(_, entity)| query.has(entity))
+                    .map(move |(depth, entity)| Delivery {
+                        entity,
+                        depth,
+                        target,
+                    })
+            })
+        }
+    }
+}
+
+pub mod record {
+    use super::*;
+    use entia_serialize::{
+        deserialize::Deserialize,
+        deserializer::{Deserializer, Item, List},
+    };
+    use std::result;
+
+    /// The messages that were recorded along with the frame in which they were emitted. It serializes as a list of
+    /// `(frame, message)` tuples such that it can be deserialized as a `Replay`.
+    #[derive(Debug, Clone, Default)]
+    pub struct Recording(pub Vec<(usize, Node)>);
+
+    /// The messages to give to `World::replay` along with the frame in which they must be emitted.
+    #[derive(Debug, Clone, Default)]
+    pub struct Replay<M>(pub Vec<(usize, M)>);
+
+    impl Serialize for Recording {
+        fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Value, S::Error> {
+            serializer.slice(&self.0)
+        }
+    }
+
+    impl<M: Default> Deserialize for &mut Replay<M>
+    where
+        for<'a> &'a mut M: Deserialize,
+    {
+        type Value = ();
+
+        fn deserialize<D: Deserializer>(self, deserializer: D) -> result::Result<(), D::Error> {
+            let mut list = deserializer.list()?;
+            self.0.clear();
+            while let Some(item) = list.item()? {
+                let mut entry = (0, M::default());
+                item.value(&mut entry)?;
+                self.0.push(entry);
+            }
+            Ok(())
+        }
+    }
+
+    impl<M> IntoIterator for Replay<M> {
+        type Item = (usize, M);
+        type IntoIter = std::vec::IntoIter<(usize, M)>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.into_iter()
+        }
+    }
+
+    impl World {
+        /// Records the messages of type `M` as they are emitted, replacing the current recording (if any).
+        pub fn record<M: Message + Serialize>(&mut self) {
+            inner::<M>(self).recorder = Some(Recorder {
+                serialize: |message| message.serialize(NodeSerializer).ok(),
+                messages: Vec::new(),
+                failed: false,
+            });
+        }
+
+        /// Stops recording the messages of type `M` and returns what was recorded. Fails if a message could not be
+        /// serialized since the recording would not reproduce the session.
+        pub fn take_recording<M: Message>(&mut self) -> Result<Option<Recording>> {
+            let recorder = self
+                .resources()
+                .get_mut::<Inner<M>>()
+                .and_then(|inner| inner.recorder.take());
+            match recorder {
+                Some(recorder) if recorder.failed => Err(Error::FailedToSerialize),
+                Some(recorder) => Ok(Some(Recording(recorder.messages))),
+                None => Ok(None),
+            }
+        }
+
+        /// Ignores the messages of live emitters of type `M` and emits the `messages` in their frame (see `Frame`)
+        /// instead. As with `Emit::at`, they are delivered at the beginning of their frame.
+        pub fn replay<M: Message>(&mut self, messages: impl IntoIterator<Item = (usize, M)>) {
+            let inner = inner::<M>(self);
+            inner.replay = true;
+            for (frame, message) in messages {
+                inner.delay(frame, true, message);
+            }
+        }
+
+        /// Restores the live emitters of type `M`. Replayed messages that were not emitted yet are dropped while pending
+        /// live delayed messages are kept.
+        pub fn resume<M: Message>(&mut self) {
+            if let Some(inner) = self.resources().get_mut::<Inner<M>>() {
+                inner.replay = false;
+                for slot in inner.wheel.iter_mut() {
+                    slot.retain(|delayed| !delayed.replayed);
+                }
+            }
+        }
+    }
+}
+
+pub mod bridge {
+    use super::*;
+
+    /// Sends messages into the world from any thread. The messages are emitted at the beginning of the next frame or
+    /// when an injector runs. During a replay (see `World::replay`), they are held until `World::resume`.
+    pub struct MessageSender<T>(Sender<T>);
+
+    /// Receives the messages that are emitted in the world on any thread.
+    pub struct Outbox<T>(mpsc::Receiver<T>);
+
+    impl<T> MessageSender<T> {
+        /// Returns `false` if the world has been dropped.
+        #[inline]
+        pub fn send(&self, message: T) -> bool {
+            self.0.send(message).is_ok()
+        }
+    }
+
+    impl<T> Clone for MessageSender<T> {
+        #[inline]
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl<T> Outbox<T> {
+        /// Returns the next message without blocking.
+        #[inline]
+        pub fn try_receive(&self) -> Option<T> {
+            self.0.try_recv().ok()
+        }
+
+        /// Waits for the next message. Returns `None` if the world has been dropped.
+        #[inline]
+        pub fn receive(&self) -> Option<T> {
+            self.0.recv().ok()
+        }
+
+        /// Iterates over the messages that were already emitted without blocking.
+        #[inline]
+        pub fn drain(&self) -> impl Iterator<Item = T> + '_ {
+            self.0.try_iter()
+        }
+    }
+
+    impl World {
+        /// Creates a `MessageSender` that feeds the inbox of the messages of type `M`.
+        pub fn sender<M: Message>(&mut self) -> MessageSender<M> {
+            let (sender, _) = inner::<M>(self).inbox.get_or_insert_with(|| {
+                let (sender, receiver) = mpsc::channel();
+                (sender, Mutex::new(receiver))
+            });
+            MessageSender(sender.clone())
+        }
+
+        /// Creates an `Outbox` that receives a clone of every message of type `M` that is emitted from now on.
+        pub fn outbox<M: Message + Clone>(&mut self) -> Outbox<M> {
+            let (sender, receiver) = mpsc::channel();
+            inner::<M>(self).outboxes.push(Box::new(move |message: &M| {
+                sender.send(message.clone()).is_ok()
+            }));
+            Outbox(receiver)
         }
     }
 }
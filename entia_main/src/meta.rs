@@ -1,9 +1,15 @@
 use crate::{
+    describe::Descriptions,
     entity::Entity,
     error::{Error, Result},
-    resource::Resource,
+    transfer::Remap,
+    Resource,
 };
 use entia_core::{Maybe, Wrap};
+use entia_serialize::{
+    deserialize::Deserialize,
+    node::{self, deserialize::NodeDeserializer, Node},
+};
 use std::{
     any::{type_name, Any, TypeId},
     collections::HashMap,
@@ -11,16 +17,22 @@ use std::{
     mem::{needs_drop, size_of, ManuallyDrop, MaybeUninit},
     ops::Deref,
     ptr::{copy, drop_in_place, slice_from_raw_parts_mut, NonNull},
-    sync::Arc,
+    result,
+    sync::{Arc, Weak},
 };
 
 type Module = dyn Any + Send + Sync;
 
-#[derive(Debug)]
+#[derive(Resource, Debug)]
 pub struct Metas {
     entity: Arc<Meta>,
     metas: Vec<Arc<Meta>>,
     indices: HashMap<TypeId, usize>,
+    names: HashMap<String, usize>,
+    /// The components of the entities of the loaded prefabs, which are declared by the templates that create them. A
+    /// shape is kept as long as a prefab that has it is alive.
+    shapes: Vec<Weak<[Arc<Meta>]>>,
+    descriptions: Descriptions,
 }
 
 #[derive(Debug)]
@@ -34,23 +46,44 @@ pub struct Meta {
     pub(crate) defaulter: Option<Defaulter>,
     pub(crate) cloner: Option<Cloner>,
     pub(crate) formatter: Option<Formatter>,
+    pub(crate) remapper: Option<Remapper>,
+    pub(crate) deserializer: Option<Deserializer>,
     modules: HashMap<TypeId, Box<Module>>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct Defaulter {
-    pub default: unsafe fn(target: (NonNull<()>, usize), count: usize),
+pub struct Defaulter {
+    pub(crate) default: unsafe fn(target: (NonNull<()>, usize), count: usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Cloner {
+    pub(crate) clone:
+        unsafe fn(source: (NonNull<()>, usize), target: (NonNull<()>, usize), count: usize),
+    pub(crate) fill:
+        unsafe fn(source: (NonNull<()>, usize), target: (NonNull<()>, usize), count: usize),
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct Cloner {
-    pub clone: unsafe fn(source: (NonNull<()>, usize), target: (NonNull<()>, usize), count: usize),
-    pub fill: unsafe fn(source: (NonNull<()>, usize), target: (NonNull<()>, usize), count: usize),
+pub struct Formatter {
+    pub(crate) format: unsafe fn(source: NonNull<()>, index: usize) -> String,
 }
 
+/// Deserializes values from a `Node` through their implementation of `Deserialize for &mut T`.
 #[derive(Debug, Clone)]
-pub(crate) struct Formatter {
-    pub format: unsafe fn(source: NonNull<()>, index: usize) -> String,
+pub struct Deserializer {
+    /// Writes the default value before deserializing onto it such that the target is initialized even on failure.
+    pub(crate) new:
+        unsafe fn(node: &Node, target: (NonNull<()>, usize)) -> result::Result<(), node::Error>,
+    /// Deserializes onto an initialized target such that only the fields present in the node are changed.
+    pub(crate) apply:
+        unsafe fn(node: &Node, target: (NonNull<()>, usize)) -> result::Result<(), node::Error>,
+}
+
+/// Fixes the `Entity` values held by a type after they have been transferred to another `World`.
+#[derive(Clone)]
+pub struct Remapper {
+    pub(crate) remap: Arc<dyn Fn((NonNull<()>, usize), usize, &Remap) + Send + Sync>,
 }
 
 impl Metas {
@@ -58,6 +91,11 @@ impl Metas {
         self.entity.clone()
     }
 
+    #[inline]
+    pub fn descriptions(&self) -> &Descriptions {
+        &self.descriptions
+    }
+
     pub fn get<T: Send + Sync + 'static>(&self) -> Result<Arc<Meta>> {
         self.get_with(TypeId::of::<T>())
     }
@@ -69,6 +107,57 @@ impl Metas {
         }
     }
 
+    pub fn get_named(&self, name: &str) -> Result<Arc<Meta>> {
+        match self.names.get(name) {
+            Some(&index) => Ok(self.metas[index].clone()),
+            None => Err(Error::MissingName { name: name.into() }),
+        }
+    }
+
+    /// Registers the `meta` under the `name` such that data such as prefabs may refer to it.
+    pub fn name(&mut self, name: impl Into<String>, meta: Arc<Meta>) -> Arc<Meta> {
+        let meta = self.get_or_insert(meta);
+        self.names
+            .insert(name.into(), self.indices[&meta.identifier()]);
+        meta
+    }
+
+    #[inline]
+    pub fn shapes(&self) -> impl Iterator<Item = Arc<[Arc<Meta>]>> + '_ {
+        self.shapes.iter().filter_map(Weak::upgrade)
+    }
+
+    /// Registers the components of the entity of a prefab unless the same components were already registered. The
+    /// shapes of the prefabs that were dropped are removed.
+    pub(crate) fn shape(&mut self, metas: Vec<Arc<Meta>>) -> Arc<[Arc<Meta>]> {
+        self.shapes.retain(|shape| shape.strong_count() > 0);
+        let same = |shape: &Arc<[Arc<Meta>]>| {
+            shape.len() == metas.len()
+                && shape.iter().all(|meta| {
+                    metas
+                        .iter()
+                        .any(|other| other.identifier() == meta.identifier())
+                })
+        };
+        if let Some(shape) = self.shapes().find(same) {
+            return shape;
+        }
+        let shape: Arc<[Arc<Meta>]> = metas.into();
+        self.shapes.push(Arc::downgrade(&shape));
+        shape
+    }
+
+    pub fn get_or_insert(&mut self, meta: Arc<Meta>) -> Arc<Meta> {
+        match self.get_with(meta.identifier()) {
+            Ok(meta) => meta,
+            Err(_) => {
+                self.indices.insert(meta.identifier(), self.metas.len());
+                self.metas.push(meta.clone());
+                meta
+            }
+        }
+    }
+
     pub fn get_or_add<T: Send + Sync + 'static>(
         &mut self,
         add: impl FnOnce() -> Meta,
@@ -96,12 +185,13 @@ impl Default for Metas {
             entity,
             metas,
             indices,
+            names: HashMap::new(),
+            shapes: Vec::new(),
+            descriptions: Descriptions::default(),
         }
     }
 }
 
-impl Resource for Metas {}
-
 impl Deref for Metas {
     type Target = [Arc<Meta>];
 
@@ -148,9 +238,12 @@ impl Meta {
             defaulter: None,
             cloner: None,
             formatter: None,
+            remapper: None,
+            deserializer: None,
             modules: modules
                 .into_iter()
-                .map(|module| (module.type_id(), module))
+                // 'Any::type_id' must be called on the boxed value, not on the 'Box' itself.
+                .map(|module| (Any::type_id(&*module), module))
                 .collect(),
         };
         meta.reset();
@@ -231,6 +324,8 @@ impl Meta {
         self.defaulter = self.get().cloned();
         self.cloner = self.get().cloned();
         self.formatter = self.get().cloned();
+        self.remapper = self.get().cloned();
+        self.deserializer = self.get().cloned();
     }
 }
 
@@ -299,7 +394,7 @@ impl Formatter {
     pub fn new<T: fmt::Debug>() -> Self {
         Self {
             format: |source, index| unsafe {
-                format!("{:?}", &*source.as_ptr().cast::<T>().add(index))
+                format!("{:?}", *source.as_ptr().cast::<T>().add(index))
             },
         }
     }
@@ -311,6 +406,57 @@ impl<T: fmt::Debug> Maybe<Formatter> for Wrap<Formatter, T> {
     }
 }
 
+impl Deserializer {
+    pub fn new<T: Default>() -> Self
+    where
+        for<'a> &'a mut T: Deserialize,
+    {
+        Self {
+            new: |node, target| unsafe {
+                let target = target.0.as_ptr().cast::<T>().add(target.1);
+                target.write(T::default());
+                (&mut *target).deserialize(NodeDeserializer(node.clone()))?;
+                Ok(())
+            },
+            apply: |node, target| unsafe {
+                let target = &mut *target.0.as_ptr().cast::<T>().add(target.1);
+                target.deserialize(NodeDeserializer(node.clone()))?;
+                Ok(())
+            },
+        }
+    }
+}
+
+impl<T: Default> Maybe<Deserializer> for Wrap<Deserializer, T>
+where
+    for<'a> &'a mut T: Deserialize,
+{
+    fn maybe(self) -> Option<Deserializer> {
+        Some(Deserializer::new::<T>())
+    }
+}
+
+impl Remapper {
+    pub fn new<T: Send + Sync + 'static, F: Fn(&mut T, &Remap) + Send + Sync + 'static>(
+        remap: F,
+    ) -> Self {
+        Self {
+            remap: Arc::new(move |target, count, map| unsafe {
+                let target = target.0.as_ptr().cast::<T>().add(target.1);
+                for i in 0..count {
+                    remap(&mut *target.add(i), map);
+                }
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for Remapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Remapper").finish_non_exhaustive()
+    }
+}
+
 #[macro_export]
 macro_rules! meta {
     ($t:ty) => {{
@@ -328,6 +474,11 @@ macro_rules! meta {
         if let Some(module) = $crate::core::Wrap::<$crate::meta::Formatter, $t>::default().maybe() {
             modules.push(std::boxed::Box::new(module));
         }
+        if let Some(module) =
+            $crate::core::Wrap::<$crate::meta::Deserializer, $t>::default().maybe()
+        {
+            modules.push(std::boxed::Box::new(module));
+        }
         $crate::meta::Meta::new::<$t, _>(modules)
     }};
 }
@@ -0,0 +1,184 @@
+use crate::{
+    depend::{Dependency, Order},
+    error::{Error, Result},
+    identify,
+    inject::{Adapt, Context, Get, Inject},
+    world::World,
+};
+use std::{
+    any::{type_name, Any, TypeId},
+    cell::UnsafeCell,
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+    thread::{self, ThreadId},
+};
+
+/// Gives access to a thread-pinned resource that is not required to be `Send` or `Sync` (such as a window handle or an
+/// audio context). A `Runner` executes the systems that inject it on the thread that calls `Runner::run`.
+pub struct Pinned<'a, T>(&'a mut T);
+pub struct State<T>(Arc<Slot<T>>);
+
+/// Thread-pinned resources are stored outside of `Resources` and may only be accessed from the thread that pinned them.
+#[derive(Default)]
+pub(crate) struct Pins(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+pub(crate) struct Slot<T> {
+    identifier: usize,
+    thread: ThreadId,
+    value: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: A slot is shared with other threads through the `World` and the states of systems, but its value is only
+// accessed through `Slot::value`, which panics on any thread other than the one that pinned the value, and it is only
+// dropped on that thread (it is leaked otherwise). The value itself never crosses threads.
+unsafe impl<T> Send for Slot<T> {}
+unsafe impl<T> Sync for Slot<T> {}
+
+impl Pins {
+    fn get<T: 'static>(&self) -> Option<Arc<Slot<T>>> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|slot| slot.downcast().ok())
+    }
+}
+
+impl<T> Slot<T> {
+    #[inline]
+    fn owned(&self) -> bool {
+        thread::current().id() == self.thread
+    }
+
+    /// # Safety
+    /// No other reference to the value may be alive.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn value(&self) -> &mut Option<T> {
+        assert!(
+            self.owned(),
+            "Pinned resource '{}' must be accessed from the thread that pinned it.",
+            type_name::<T>()
+        );
+        &mut *self.value.get()
+    }
+}
+
+impl<T> Drop for Slot<T> {
+    fn drop(&mut self) {
+        // A value that is not `Send` can not be dropped on another thread; it is leaked instead.
+        if !self.owned() {
+            std::mem::forget(self.value.get_mut().take());
+        }
+    }
+}
+
+impl World {
+    /// Pins the `value` to the current thread, replacing the previously pinned value of the same type (if any).
+    pub fn pin<T: 'static>(&mut self, value: T) -> Option<T> {
+        let identifier = identify();
+        let description = format!("pinned `{}`", type_name::<T>());
+        self.descriptions().describe(identifier, description);
+        let slot = Slot {
+            identifier,
+            thread: thread::current().id(),
+            value: Some(value).into(),
+        };
+        let previous = self.pins().0.insert(TypeId::of::<T>(), Arc::new(slot));
+        self.modify();
+        let slot = previous?.downcast::<Slot<T>>().ok()?;
+        self.descriptions().forget(slot.identifier);
+        take(&slot)
+    }
+
+    /// Removes the pinned value of type `T`. The value is only returned on the thread that pinned it.
+    pub fn unpin<T: 'static>(&mut self) -> Option<T> {
+        let slot = self.pins().0.remove(&TypeId::of::<T>())?;
+        self.modify();
+        let slot = slot.downcast::<Slot<T>>().ok()?;
+        self.descriptions().forget(slot.identifier);
+        take(&slot)
+    }
+}
+
+fn take<T>(slot: &Slot<T>) -> Option<T> {
+    if slot.owned() {
+        // SAFETY: 'World' is borrowed mutably so no system is running.
+        unsafe { slot.value() }.take()
+    } else {
+        None
+    }
+}
+
+impl<T> Deref for Pinned<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<T> DerefMut for Pinned<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+unsafe impl<T: 'static> Inject for Pinned<'_, T> {
+    type Input = ();
+    type State = State<T>;
+
+    fn initialize<A: Adapt<Self::State>>(
+        _: Self::Input,
+        mut context: Context<Self::State, A>,
+    ) -> Result<Self::State> {
+        let slot = context
+            .world()
+            .pins()
+            .get::<T>()
+            .ok_or(Error::MissingResource {
+                name: type_name::<T>(),
+                identifier: TypeId::of::<T>(),
+            })?;
+        context.schedule(|state, mut schedule| {
+            // The value may have been unpinned or replaced since the last schedule.
+            let slot = schedule.context().world().pins().get::<T>();
+            let pinned = slot.is_some();
+            if let Some(slot) = slot {
+                state.0 = slot;
+            }
+            // The failure of the pre-run skips the run of the system (see `Schedule::pre`) such that it only accesses
+            // a value that is present.
+            schedule.pre(
+                move |state| match unsafe { state.0.value() } {
+                    Some(_) if pinned => Ok(()),
+                    _ => Err(Error::MissingResource {
+                        name: type_name::<T>(),
+                        identifier: TypeId::of::<T>(),
+                    }),
+                },
+                Self::depend(state),
+            );
+        });
+        Ok(State(slot))
+    }
+
+    fn depend(state: &Self::State) -> Vec<Dependency> {
+        vec![
+            Dependency::Thread,
+            Dependency::write_at(state.0.identifier, Order::Strict),
+        ]
+    }
+}
+
+impl<'a, T: 'static> Get<'a> for State<T> {
+    type Item = Pinned<'a, T>;
+
+    #[inline]
+    unsafe fn get(&'a mut self) -> Self::Item {
+        // The pre-run of the state has verified that the value is present.
+        Pinned(self.0.value().as_mut().unwrap_unchecked())
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    component::Component,
+    error::{Error, Result},
+    meta::{Meta, Metas},
+    segment::Segments,
+    store::Store,
+    template::{ApplyContext, CountContext, DeclareContext, InitializeContext, Template},
+    world::World,
+    Resource,
+};
+use entia_serialize::node::Node;
+use std::{any::TypeId, collections::HashMap, sync::Arc};
+
+/// A hierarchy of entities loaded from a `Node` of the form `{ "components": { "Position": ... }, "children": [...] }`.
+/// Component names are resolved through the names registered in `Metas` (see `World::register`).
+///
+/// A prefab may also name a `"base"` prefab (see `World::register_prefab`) from which it inherits its components and
+/// children. Its `"components"` replace the inherited ones while its `"overrides"` are deserialized onto them such
+/// that only the fields that they hold change.
+///
+/// A prefab is a `Template` that chooses the components of its entity, so it may be combined with templates that
+/// spawn children but not with templates that add components to the same entity (such as `Add`). A `Create` declares
+/// the prefabs of its world that were loaded before it was initialized and fails to create the others.
+#[derive(Clone)]
+pub struct Prefab {
+    world: usize,
+    entry: Arc<Entry>,
+}
+
+#[derive(Resource, Default)]
+struct Prefabs(HashMap<String, Prefab>);
+
+#[derive(Clone)]
+struct Entry {
+    segment: usize,
+    /// Keeps the shape of the entity declared by the `Create`s that are initialized while the prefab is alive.
+    _shape: Arc<[Arc<Meta>]>,
+    /// The first node of each component is deserialized onto its default value and the others are applied in order.
+    components: Vec<(Arc<Meta>, Vec<Node>)>,
+    children: Vec<Entry>,
+}
+
+pub struct State {
+    world: usize,
+    /// The declared segments by the index of their segment in the world.
+    shapes: HashMap<usize, Shape>,
+    /// Whether other templates add components to the entity of the prefab.
+    shared: bool,
+}
+
+struct Shape {
+    segment: usize,
+    stores: HashMap<TypeId, Arc<Store>>,
+}
+
+impl World {
+    /// Registers the component `C` under the `name` such that prefabs may refer to it.
+    pub fn register<C: Component>(&mut self, name: impl Into<String>) -> Result {
+        let store = unsafe { self.resources().get_store::<Metas>(None)? };
+        let metas = unsafe { store.get::<Metas>(0) };
+        let meta = metas.get_or_add::<C>(C::meta);
+        metas.name(name, meta);
+        Ok(())
+    }
+
+    /// Registers the `prefab` under the `name` such that other prefabs may use it as their base.
+    pub fn register_prefab(&mut self, name: impl Into<String>, prefab: &Prefab) -> Result {
+        if prefab.world != self.identifier() {
+            return Err(Error::WrongWorld {
+                expected: self.identifier(),
+                actual: prefab.world,
+            });
+        }
+        let store = unsafe { self.resources().get_store::<Prefabs>(None)? };
+        let prefabs = unsafe { store.get::<Prefabs>(0) };
+        prefabs.0.insert(name.into(), prefab.clone());
+        Ok(())
+    }
+
+    /// Resolves the components of the `node` and adds the segments that its entities will belong to. The prefab must be
+    /// loaded before the systems that create it are initialized such that their `Create` declares its segments.
+    pub fn prefab(&mut self, node: &Node) -> Result<Prefab> {
+        let resources = self.resources();
+        let (metas, prefabs, segments) = unsafe {
+            (
+                resources.get_store::<Metas>(None)?,
+                resources.get_store::<Prefabs>(None)?,
+                resources.get_store::<Segments>(None)?,
+            )
+        };
+        let (metas, prefabs, segments) = unsafe {
+            (
+                metas.get::<Metas>(0),
+                prefabs.get::<Prefabs>(0),
+                segments.get::<Segments>(0),
+            )
+        };
+        let count = segments.len();
+        let entry = Entry::load(node, metas, prefabs, segments)?;
+        if segments.len() > count {
+            self.modify();
+        }
+        Ok(Prefab {
+            world: self.identifier(),
+            entry: Arc::new(entry),
+        })
+    }
+}
+
+impl Entry {
+    fn load(
+        node: &Node,
+        metas: &mut Metas,
+        prefabs: &Prefabs,
+        segments: &mut Segments,
+    ) -> Result<Self> {
+        let (mut components, mut children) = match node.get("base") {
+            Some(base) => {
+                let name = base.string().ok_or(Error::InvalidPrefab)?;
+                match prefabs.0.get(name) {
+                    Some(Prefab { entry, .. }) => {
+                        (entry.components.clone(), entry.children.clone())
+                    }
+                    None => return Err(Error::MissingPrefab { name: name.into() }),
+                }
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
+        for (meta, node) in Self::named(node, "components", metas)? {
+            match components
+                .iter_mut()
+                .find(|(other, _)| other.identifier() == meta.identifier())
+            {
+                Some((_, nodes)) => *nodes = vec![node.clone()],
+                None => components.push((meta, vec![node.clone()])),
+            }
+        }
+        for (meta, node) in Self::named(node, "overrides", metas)? {
+            match components
+                .iter_mut()
+                .find(|(other, _)| other.identifier() == meta.identifier())
+            {
+                Some((_, nodes)) => nodes.push(node.clone()),
+                None => return Err(Error::MissingOverride { name: meta.name() }),
+            }
+        }
+        // Deserializes each component once such that invalid data is reported here rather than when instantiated.
+        for (meta, nodes) in components.iter() {
+            Self::validate(meta, nodes)?;
+        }
+
+        if let Some(node) = node.get("children") {
+            for node in node.items().ok_or(Error::InvalidPrefab)? {
+                children.push(Self::load(node, metas, prefabs, segments)?);
+            }
+        }
+
+        let shape: Vec<_> = components.iter().map(|(meta, _)| meta.clone()).collect();
+        let segment = segments.get_or_add(shape.iter().cloned(), metas).index();
+        Ok(Self {
+            segment,
+            _shape: metas.shape(shape),
+            components,
+            children,
+        })
+    }
+
+    fn named<'a>(node: &'a Node, key: &str, metas: &Metas) -> Result<Vec<(Arc<Meta>, &'a Node)>> {
+        let mut named = Vec::new();
+        if let Some(node) = node.get(key) {
+            for (name, node) in node.pairs().ok_or(Error::InvalidPrefab)? {
+                let meta = metas.get_named(name.string().ok_or(Error::InvalidPrefab)?)?;
+                if meta.deserializer.is_none() {
+                    return Err(Error::MissingDeserialize { name: meta.name() });
+                }
+                named.push((meta, node));
+            }
+        }
+        Ok(named)
+    }
+
+    fn validate(meta: &Arc<Meta>, nodes: &[Node]) -> Result {
+        unsafe {
+            let store = Store::new(meta.clone(), 1);
+            let result = Self::deserialize(&store, 0, nodes);
+            store.free(1, 1);
+            result
+        }
+    }
+
+    /// SAFETY: The value at `index` must be dropped or uninitialized. It is initialized even when this fails.
+    unsafe fn deserialize(store: &Store, index: usize, nodes: &[Node]) -> Result {
+        match nodes.split_first() {
+            Some((first, rest)) => {
+                let mut result = store.deserialize(index, first);
+                for node in rest {
+                    result = result.and_then(|_| store.apply(index, node));
+                }
+                result
+            }
+            None => Err(Error::InvalidPrefab),
+        }
+    }
+
+    fn count(&self, state: &State, mut context: CountContext) {
+        for child in self.children.iter() {
+            match state.shape(child) {
+                Ok(shape) => context.child(shape.segment, |context| child.count(state, context)),
+                Err(error) => return context.fail(error),
+            }
+        }
+    }
+
+    fn apply(&self, state: &State, mut context: ApplyContext) {
+        // The shapes of the entries were checked when the prefab was counted.
+        let shape = &state.shapes[&self.segment];
+        for (meta, nodes) in self.components.iter() {
+            let store = &shape.stores[&meta.identifier()];
+            // The component is initialized even if this fails such that the other components may still be applied.
+            if let Err(error) = unsafe { Self::deserialize(store, context.store_index(), nodes) } {
+                context.fail(error);
+            }
+        }
+        for child in self.children.iter() {
+            context.child(|context| child.apply(state, context));
+        }
+    }
+}
+
+impl State {
+    fn shape(&self, entry: &Entry) -> Result<&Shape> {
+        self.shapes
+            .get(&entry.segment)
+            .ok_or(Error::UndeclaredPrefab)
+    }
+}
+
+impl Template for Prefab {
+    type Input = (usize, Vec<usize>);
+    type State = State;
+
+    fn declare(mut context: DeclareContext) -> Self::Input {
+        let shapes: Vec<_> = context.metas().shapes().collect();
+        let indices = shapes
+            .into_iter()
+            .map(|shape| {
+                context.child(|index, mut context| {
+                    for meta in shape.iter() {
+                        context.meta_with(meta.clone());
+                    }
+                    index
+                })
+            })
+            .collect();
+        (context.world(), indices)
+    }
+
+    fn initialize((world, indices): Self::Input, mut context: InitializeContext) -> Self::State {
+        // Only the entity meta belongs to the segment if no other template adds a component to the entity.
+        let shared = context.segment().metas().len() > 1;
+        let mut shapes = HashMap::new();
+        for index in indices {
+            context.child(index, |segment, context| {
+                let stores = context
+                    .segment()
+                    .metas()
+                    .filter_map(|meta| {
+                        let store = context.segment().store(meta.identifier()).ok()?;
+                        Some((meta.identifier(), store.clone()))
+                    })
+                    .collect();
+                shapes.insert(context.segment().index(), Shape { segment, stores });
+            });
+        }
+        State {
+            world,
+            shapes,
+            shared,
+        }
+    }
+
+    fn static_count(state: &Self::State, _: CountContext) -> Result<bool> {
+        if state.shared {
+            Err(Error::PrefabMustOwnEntity)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn dynamic_count(&self, state: &Self::State, mut context: CountContext) {
+        if self.world != state.world {
+            return context.fail(Error::WrongWorld {
+                expected: state.world,
+                actual: self.world,
+            });
+        }
+        match state.shape(&self.entry) {
+            Ok(shape) => {
+                context.relocate(shape.segment);
+                self.entry.count(state, context);
+            }
+            Err(error) => context.fail(error),
+        }
+    }
+
+    #[inline]
+    fn apply(self, state: &Self::State, context: ApplyContext) {
+        self.entry.apply(state, context);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::{self, Error};
+use entia_serialize::{
+    json::serialize::JsonSerializer,
+    serialize::Serialize,
+    serializer::{Map, Serializer},
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Records the timing of the runs executed by a `Runner`. It is enabled with `Runner::profile` and costs nothing when
+/// disabled. Every thread records its events in its own buffer such that threads do not contend for it.
+#[derive(Debug)]
+pub struct Profiler {
+    origin: Instant,
+    frame: usize,
+    /// The names of the systems by identifier. Names are kept after their system is removed to name its past events.
+    pub(crate) names: HashMap<usize, String>,
+    events: Vec<Mutex<Vec<Event>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A run was executing.
+    Run,
+    /// A thread was waiting for the blockers of a run to be done.
+    Wait,
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub kind: Kind,
+    /// The identifier of the system that owns the run or `None` for the synchronization points between stages.
+    pub system: Option<usize>,
+    /// The index of the pool thread that recorded the event or `0` for the thread that calls `Runner::run`.
+    pub thread: usize,
+    pub frame: usize,
+    /// Relative to the creation of the profiler.
+    pub start: Duration,
+    pub end: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub system: String,
+    pub count: usize,
+    pub total: Duration,
+    pub average: Duration,
+    /// The average time spent waiting for blockers before each run.
+    pub wait: Duration,
+}
+
+struct Trace<'a>(&'a Profiler);
+struct Record<'a>(&'a Profiler, &'a Event);
+struct Frame(usize);
+
+impl Profiler {
+    /// Creates a profiler for a runner with `threads` pool threads in addition to the thread that calls `Runner::run`.
+    pub(crate) fn new(threads: usize) -> Self {
+        Self {
+            origin: Instant::now(),
+            frame: 0,
+            names: HashMap::new(),
+            events: (0..=threads).map(|_| Mutex::new(Vec::new())).collect(),
+        }
+    }
+
+    #[inline]
+    pub const fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns the events of all threads ordered by their start.
+    pub fn events(&self) -> Vec<Event> {
+        let mut events: Vec<_> = self
+            .events
+            .iter()
+            .flat_map(|events| events.lock().clone())
+            .collect();
+        events.sort_by_key(|event| event.start);
+        events
+    }
+
+    pub fn name(&self, event: &Event) -> &str {
+        match event.system {
+            Some(system) => self.names.get(&system).map_or("", String::as_str),
+            None => "barrier",
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for events in self.events.iter_mut() {
+            events.get_mut().clear();
+        }
+    }
+
+    /// Formats the recorded events as Chrome Trace Event JSON.
+    pub fn trace(&self) -> error::Result<String> {
+        JsonSerializer::serialize(&Trace(self)).map_err(|_| Error::FailedToSerialize)
+    }
+
+    /// Averages the recorded events per system, in the order in which the systems were created.
+    pub fn summary(&self) -> Vec<Summary> {
+        let mut totals = HashMap::<_, (usize, Duration, Duration)>::new();
+        for event in self.events() {
+            if let Some(system) = event.system {
+                let total = totals.entry(system).or_default();
+                match event.kind {
+                    Kind::Run => {
+                        total.0 += 1;
+                        total.1 += event.end - event.start;
+                    }
+                    Kind::Wait => total.2 += event.end - event.start,
+                }
+            }
+        }
+
+        let mut summaries: Vec<_> = totals.into_iter().collect();
+        summaries.sort_by_key(|&(system, _)| system);
+        summaries
+            .into_iter()
+            .map(|(system, (count, total, wait))| {
+                let divisor = count.max(1) as u32;
+                Summary {
+                    system: self.names.get(&system).cloned().unwrap_or_default(),
+                    count,
+                    total,
+                    average: total / divisor,
+                    wait: wait / divisor,
+                }
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self) {
+        self.frame += 1;
+    }
+
+    pub(crate) fn record(&self, kind: Kind, system: Option<usize>, start: Instant, end: Instant) {
+        let thread = rayon::current_thread_index().map_or(0, |index| index + 1);
+        let event = Event {
+            kind,
+            system,
+            thread,
+            frame: self.frame,
+            start: start.saturating_duration_since(self.origin),
+            end: end.saturating_duration_since(self.origin),
+        };
+        self.events[thread.min(self.events.len() - 1)]
+            .lock()
+            .push(event);
+    }
+}
+
+impl Serialize for Trace<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        let events = self.0.events();
+        let events: Vec<_> = events.iter().map(|event| Record(self.0, event)).collect();
+        serializer
+            .map()?
+            .pair("traceEvents", events)?
+            .pair("displayTimeUnit", "ms")?
+            .end()
+    }
+}
+
+impl Serialize for Record<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        let Record(profiler, event) = *self;
+        let category = match event.kind {
+            Kind::Run => "run",
+            Kind::Wait => "wait",
+        };
+        serializer
+            .map()?
+            .pair("name", profiler.name(event))?
+            .pair("cat", category)?
+            .pair("ph", "X")?
+            .pair("ts", event.start.as_secs_f64() * 1_000_000.0)?
+            .pair("dur", (event.end - event.start).as_secs_f64() * 1_000_000.0)?
+            .pair("pid", 0)?
+            .pair("tid", event.thread)?
+            .pair("args", Frame(event.frame))?
+            .end()
+    }
+}
+
+impl Serialize for Frame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
+        serializer.map()?.pair("frame", self.0)?.end()
+    }
+}
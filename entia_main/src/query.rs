@@ -6,9 +6,9 @@ use crate::{
     filter::Filter,
     inject::{Adapt, Context, Get, Inject},
     item::{At, Item},
-    resource::Resource,
     resource::{Read, Write},
     segment::Segments,
+    Resource,
 };
 use std::{
     any::type_name,
@@ -29,15 +29,14 @@ pub struct State<I: Item, F> {
     pub(crate) entities: Read<Entities>,
 }
 
+#[derive(Resource)]
 pub struct Inner<S, F> {
     pub(crate) segments: Vec<usize>,
     pub(crate) states: Vec<(S, usize)>,
     _marker: PhantomData<fn(F)>,
 }
 
-impl<S: Send + Sync + 'static, F: 'static> Resource for Inner<S, F> {}
-
-impl<S, F: 'static> Default for Inner<S, F> {
+impl<S, F> Default for Inner<S, F> {
     fn default() -> Self {
         Self {
             segments: Vec::new(),
@@ -47,7 +46,7 @@ impl<S, F: 'static> Default for Inner<S, F> {
     }
 }
 
-impl<'a, I: Item, F> fmt::Debug for Query<'a, I, F>
+impl<'a, I: Item + 'a, F: 'a> fmt::Debug for Query<'a, I, F>
 where
     <&'a Self as IntoIterator>::Item: fmt::Debug,
 {
@@ -135,6 +134,14 @@ macro_rules! iter {
     }};
 }
 
+impl<I: Item, F> Query<'_, I, F> {
+    /// Whether the `entity` is matched by the query.
+    #[inline]
+    pub fn has<E: Into<Entity>>(&self, entity: E) -> bool {
+        self.get(entity).is_some()
+    }
+}
+
 macro_rules! iterator {
     ($at:ident, $chunks:ident, $iter:ident, $each:ident, $get:ident, $item:ident, [$($mut:tt)?]) => {
         impl<'a, I: Item, F> Query<'a, I, F> {
@@ -143,7 +150,7 @@ macro_rules! iterator {
                 iter!(self, $at, [$($mut)?])
             }
 
-            pub fn $chunks(& $($mut)? self) -> impl DoubleEndedIterator<Item = <I::State as At<RangeFull>>::$item>
+            pub fn $chunks(& $($mut)? self) -> impl DoubleEndedIterator<Item = <I::State as At<'_, RangeFull>>::$item>
             where
                 I::State: for<'b> At<'b, RangeFull>
             {
@@ -1,12 +1,13 @@
 use crate::{
     depend::{Dependency, Order},
-    error::Result,
+    error::{Error, Result},
     inject::{Adapt, Context, Get},
     meta::Meta,
     store::Store,
     Inject,
 };
 use std::{
+    any::TypeId,
     marker::PhantomData,
     ops::{Deref, DerefMut},
     sync::Arc,
@@ -15,7 +16,14 @@ use std::{
 pub struct Write<T>(Arc<Store>, PhantomData<T>);
 pub struct Read<T>(Write<T>);
 
-pub trait Resource: Default + Send + Sync + 'static {
+/// A resource does not need to implement `Default`; when it does, a missing resource will be initialized with its
+/// default value on injection. Otherwise, injecting a missing `&R` or `&mut R` fails with `Error::MissingResource` and
+/// `Option<&R>` or `Option<&mut R>` should be used instead.
+///
+/// Optional modules (such as `Default`) can only be detected for a concrete type, so the default implementation of
+/// `meta` detects none of them. A manual implementation for a resource that should be initialized with its default
+/// value must implement `meta` with `meta!(Self)`, as `#[derive(Resource)]` does.
+pub trait Resource: Sized + Send + Sync + 'static {
     fn meta() -> Meta {
         crate::meta!(Self)
     }
@@ -62,8 +70,25 @@ unsafe impl<R: Resource> Inject for Write<R> {
         input: Self::Input,
         mut context: Context<Self::State, A>,
     ) -> Result<Self::State> {
-        let resources = context.world().resources();
-        let store = unsafe { resources.get_store::<R, _>(|| input.unwrap_or_else(R::default)) };
+        let store = unsafe { context.world().resources().get_store::<R>(input)? };
+        let name = store.meta().name();
+        context.schedule(move |state, mut schedule| {
+            // The resource may have been removed or replaced since the last schedule. When it was removed, the state keeps
+            // the store of the removed resource but the failure of the pre-run skips the run of the system (see
+            // `Schedule::pre`) such that the store is never dereferenced.
+            match schedule.context().world().resources().store::<R>() {
+                Some(store) => state.0 = store,
+                None => schedule.pre(
+                    move |_| {
+                        Err(Error::MissingResource {
+                            name,
+                            identifier: TypeId::of::<R>(),
+                        })
+                    },
+                    [Dependency::write::<R>(Order::Strict)],
+                ),
+            }
+        });
         Ok(Self(store, PhantomData))
     }
 
@@ -84,10 +109,10 @@ impl<'a, R: Resource> Get<'a> for Write<R> {
     }
 }
 
-impl<T> Into<Read<T>> for Write<T> {
+impl<T> From<Write<T>> for Read<T> {
     #[inline]
-    fn into(self) -> Read<T> {
-        Read(self)
+    fn from(write: Write<T>) -> Self {
+        Read(write)
     }
 }
 
@@ -171,3 +196,109 @@ impl<T: Send + Sync + 'static> Deref for Read<T> {
         self.0.deref()
     }
 }
+
+unsafe impl<R: Resource> Inject for Option<&mut R> {
+    type Input = <Option<Write<R>> as Inject>::Input;
+    type State = <Option<Write<R>> as Inject>::State;
+
+    fn initialize<A: Adapt<Self::State>>(
+        input: Self::Input,
+        context: Context<Self::State, A>,
+    ) -> Result<Self::State> {
+        Option::<Write<R>>::initialize(input, context)
+    }
+
+    fn depend(state: &Self::State) -> Vec<Dependency> {
+        Option::<Write<R>>::depend(state)
+    }
+}
+
+unsafe impl<R: Resource> Inject for Option<Write<R>> {
+    type Input = ();
+    type State = Self;
+
+    fn initialize<A: Adapt<Self::State>>(
+        _: Self::Input,
+        mut context: Context<Self::State, A>,
+    ) -> Result<Self::State> {
+        context.schedule(|state, mut schedule| {
+            *state = schedule
+                .context()
+                .world()
+                .resources()
+                .store::<R>()
+                .map(|store| Write(store, PhantomData));
+        });
+        let store = context.world().resources().store::<R>();
+        Ok(store.map(|store| Write(store, PhantomData)))
+    }
+
+    fn depend(state: &Self::State) -> Vec<Dependency> {
+        match state {
+            Some(state) => Write::<R>::depend(state),
+            None => vec![Dependency::write::<R>(Order::Strict)],
+        }
+    }
+}
+
+impl<'a, R: Resource> Get<'a> for Option<Write<R>> {
+    type Item = Option<&'a mut R>;
+
+    #[inline]
+    unsafe fn get(&'a mut self) -> Self::Item {
+        Some(self.as_mut()?.get())
+    }
+}
+
+unsafe impl<R: Resource> Inject for Option<&R> {
+    type Input = <Option<Read<R>> as Inject>::Input;
+    type State = <Option<Read<R>> as Inject>::State;
+
+    fn initialize<A: Adapt<Self::State>>(
+        input: Self::Input,
+        context: Context<Self::State, A>,
+    ) -> Result<Self::State> {
+        Option::<Read<R>>::initialize(input, context)
+    }
+
+    fn depend(state: &Self::State) -> Vec<Dependency> {
+        Option::<Read<R>>::depend(state)
+    }
+}
+
+unsafe impl<R: Resource> Inject for Option<Read<R>> {
+    type Input = ();
+    type State = Self;
+
+    fn initialize<A: Adapt<Self::State>>(
+        _: Self::Input,
+        mut context: Context<Self::State, A>,
+    ) -> Result<Self::State> {
+        context.schedule(|state, mut schedule| {
+            *state = schedule
+                .context()
+                .world()
+                .resources()
+                .store::<R>()
+                .map(|store| Read(Write(store, PhantomData)));
+        });
+        let store = context.world().resources().store::<R>();
+        Ok(store.map(|store| Read(Write(store, PhantomData))))
+    }
+
+    fn depend(state: &Self::State) -> Vec<Dependency> {
+        match state {
+            Some(state) => Read::<R>::depend(state),
+            None => vec![Dependency::read::<R>(Order::Strict)],
+        }
+    }
+}
+
+impl<'a, R: Resource> Get<'a> for Option<Read<R>> {
+    type Item = Option<&'a R>;
+
+    #[inline]
+    unsafe fn get(&'a mut self) -> Self::Item {
+        Some(self.as_mut()?.get())
+    }
+}
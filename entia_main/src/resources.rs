@@ -1,28 +1,46 @@
 use crate::{
+    error::{Error, Result},
     meta::{Meta, Metas},
     resource::Resource,
     store::Store,
 };
+use entia_core::utility::short_type_name;
 use std::{any::TypeId, collections::HashMap, sync::Arc};
 
 #[derive(Default)]
-pub struct Resources(HashMap<TypeId, Arc<Store>>);
+pub struct Resources {
+    stores: HashMap<TypeId, Arc<Store>>,
+    version: usize,
+    /// Stores of removed resources that are still held by injected states. Their value has been moved out but their
+    /// allocation is kept alive until they are released such that no state ever holds a dangling pointer.
+    removed: Vec<Arc<Store>>,
+}
 
 impl Resources {
+    /// The version changes every time a resource is added or removed.
+    #[inline]
+    pub const fn version(&self) -> usize {
+        self.version
+    }
+
+    pub fn has<R: Resource>(&self) -> bool {
+        self.stores.contains_key(&TypeId::of::<R>())
+    }
+
     pub fn get<R: Resource>(&self) -> Option<&R> {
-        self.0
+        self.stores
             .get(&TypeId::of::<R>())
             .map(|store| unsafe { &*store.get(0) })
     }
 
     pub fn get_mut<R: Resource>(&mut self) -> Option<&mut R> {
-        self.0
+        self.stores
             .get(&TypeId::of::<R>())
             .map(|store| unsafe { store.get(0) })
     }
 
     pub fn set<R: Resource>(&mut self, resource: R) -> Option<R> {
-        match self.0.get(&TypeId::of::<R>()) {
+        match self.stores.get(&TypeId::of::<R>()) {
             Some(store) => Some(unsafe { store.replace(0, resource) }),
             None => {
                 let meta = self.with_metas(|metas| metas.get_or_add::<R>(R::meta));
@@ -32,44 +50,96 @@ impl Resources {
         }
     }
 
-    pub(crate) unsafe fn get_store<R: Resource, F: FnOnce() -> R>(
+    pub fn remove<R: Resource>(&mut self) -> Option<R> {
+        let store = self.stores.remove(&TypeId::of::<R>())?;
+        self.version += 1;
+        let resource = unsafe { store.data::<R>().read() };
+        // Injected states that still hold the store will be rescheduled since the version has changed.
+        self.removed.push(store);
+        self.release();
+        Some(resource)
+    }
+
+    /// Frees the stores of removed resources that are no longer held by any injected state.
+    fn release(&mut self) {
+        let mut released = Vec::new();
+        self.removed.retain(|store| {
+            if Arc::strong_count(store) > 1 {
+                true
+            } else {
+                unsafe { store.free(0, 1) };
+                released.push(store.identifier());
+                false
+            }
+        });
+        if let Some(store) = self.stores.get(&TypeId::of::<Metas>()) {
+            let metas = unsafe { store.get::<Metas>(0) };
+            for identifier in released {
+                metas.descriptions().forget(identifier);
+            }
+        }
+    }
+
+    pub(crate) fn store<R: Resource>(&self) -> Option<Arc<Store>> {
+        self.stores.get(&TypeId::of::<R>()).cloned()
+    }
+
+    /// Adds the store with the `resource` or with the default value of `R` (if it has one) when it is missing.
+    pub(crate) unsafe fn get_store<R: Resource>(
         &mut self,
-        initialize: F,
-    ) -> Arc<Store> {
-        match self.0.get(&TypeId::of::<R>()) {
-            Some(store) => store.clone(),
+        resource: Option<R>,
+    ) -> Result<Arc<Store>> {
+        match self.stores.get(&TypeId::of::<R>()) {
+            Some(store) => Ok(store.clone()),
             None => {
                 let meta = self.with_metas(|metas| metas.get_or_add::<R>(R::meta));
-                self.add_store(initialize(), meta)
+                match resource.or_else(|| meta.default()) {
+                    Some(resource) => Ok(self.add_store(resource, meta)),
+                    None => Err(Error::MissingResource {
+                        name: meta.name(),
+                        identifier: meta.identifier(),
+                    }),
+                }
             }
         }
     }
 
     fn with_metas<T>(&mut self, map: impl FnOnce(&mut Metas) -> T) -> T {
-        match self.0.get(&TypeId::of::<Metas>()) {
-            Some(store) => map(unsafe { store.get(0) }),
-            None => {
-                let mut metas = Metas::default();
-                let meta = metas.get_or_add::<Metas>(Metas::meta);
-                let store = self.add_store(metas, meta);
-                map(unsafe { store.get(0) })
-            }
+        map(self.metas())
+    }
+
+    /// Returns the `Metas` of the world, adding them if they are missing.
+    pub(crate) fn metas(&mut self) -> &mut Metas {
+        if !self.has::<Metas>() {
+            let mut metas = Metas::default();
+            let meta = metas.get_or_add::<Metas>(Metas::meta);
+            self.add_store(metas, meta);
         }
+        unsafe { self.stores[&TypeId::of::<Metas>()].get(0) }
     }
 
     fn add_store<T: Send + Sync + 'static>(&mut self, value: T, meta: Arc<Meta>) -> Arc<Store> {
         assert!(meta.is::<T>());
         let store = Arc::new(unsafe { Store::new(meta, 1) });
         unsafe { store.set(0, value) };
-        self.0.insert(TypeId::of::<T>(), store.clone());
+        self.stores.insert(TypeId::of::<T>(), store.clone());
+        self.metas().descriptions().describe(
+            store.identifier(),
+            format!("resource `{}`", short_type_name::<T>()),
+        );
+        self.version += 1;
+        self.release();
         store
     }
 }
 
 impl Drop for Resources {
     fn drop(&mut self) {
-        for (_, store) in self.0.drain() {
+        for (_, store) in self.stores.drain() {
             unsafe { store.free(1, 1) };
         }
+        for store in self.removed.drain(..) {
+            unsafe { store.free(0, 1) };
+        }
     }
 }
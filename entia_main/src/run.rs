@@ -1,50 +1,180 @@
 use crate::{
-    depend::{Conflict, Dependency, Order},
+    depend::{Conflict, Dependency, Key, Order},
     error::{Error, Result},
-    system::System,
+    graph::{self, Edge, Graph, Node},
+    message,
+    profile::{Kind as Event, Profiler},
+    stage::{Stage, Tick},
+    system::{Policy, System},
     world::World,
-    IntoSystem,
+    IntoSystem, Resource,
 };
 use entia_core::Change;
+use fastrand::Rng;
 use parking_lot::{RwLock, RwLockUpgradableReadGuard};
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::{
     any::Any,
-    collections::HashSet,
-    ops::Not,
+    collections::{HashMap, HashSet},
+    mem::{replace, take},
+    ops::{Not, Range},
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
     thread::yield_now,
+    time::{Duration, Instant},
 };
 
 pub struct Runner {
     world: usize,
     version: usize,
     systems: Vec<System>,
+    /// The identifiers of the systems that are disabled.
+    disabled: HashSet<usize>,
+    /// The consecutive failures of the runs under `Policy::Retry` by system identifier and position within their
+    /// system. They are only held while the runs are rebuilt.
+    retries: HashMap<(usize, usize), usize>,
+    stages: Vec<Stage>,
+    /// The contiguous ranges of systems in execution order. A stage that resumes after an inner stage has many segments.
+    segments: Vec<Segment>,
+    time: Option<Instant>,
+    profiler: Option<Profiler>,
+    mode: Mode,
+    random: Rng,
+    handler: Option<Box<Handler>>,
     control: bool,
-    runs: Runs,
+    runs: Box<Runs>,
     conflict: Conflict,
     pool: ThreadPool,
 }
 
-type Runs = Vec<(RwLock<(Run, State)>, Blockers)>;
+/// The index of the current frame of a world. It is incremented by `Runner::run` at the beginning of every frame.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Frame(usize);
+
+type Runs = [(RwLock<(Run, State)>, Blockers)];
+type Handler = dyn FnMut(&str, &Error) + Send + Sync;
+
+impl Frame {
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Determines how a `Runner` executes the runs of a phase. All modes respect the same strong blockers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Runs execute on the thread pool and as soon as their blockers are done.
+    #[default]
+    Parallel,
+    /// Runs execute one at a time on the calling thread in the order of the systems.
+    Sequential,
+    /// Runs execute one at a time on the calling thread in a random valid order that is determined by the seed. Running
+    /// the same systems with the same seed reproduces the same orders.
+    Shuffle(u64),
+}
 
 pub struct Run {
-    run: Box<dyn FnMut(&mut dyn Any) -> Result + Send + Sync>,
+    run: Kind,
     dependencies: Vec<Dependency>,
+    /// Whether the run prepares the state of its system (see `Schedule::pre`).
+    pre: bool,
+}
+
+enum Kind {
+    Shared(Box<dyn FnMut(&mut dyn Any) -> Result + Send + Sync>),
+    Exclusive(Box<dyn FnMut(&mut dyn Any, &mut World) -> Result + Send + Sync>),
+}
+
+struct Segment {
+    stage: usize,
+    systems: Range<usize>,
 }
 
 #[derive(Debug)]
 struct State {
+    system: usize,
+    /// The identifier of the system, which names the events of the profiler even after the system is removed.
+    identifier: usize,
+    segment: usize,
+    /// Whether the run is the synchronization point at the beginning of its segment.
+    barrier: bool,
+    /// Whether the run prepares the state of its system, in which case the runs that it blocks never execute after it
+    /// failed.
+    pre: bool,
     state: Arc<dyn Any + Send + Sync>,
+    policy: Policy,
     done: bool,
+    /// Whether the run failed or was skipped such that the runs that it blocks must be skipped in this frame.
+    skip: bool,
+    /// The number of consecutive frames in which the run failed under `Policy::Retry`.
+    failures: usize,
+    /// An error that stops the frame.
     error: Option<Error>,
+    /// An error that did not stop the frame and that is yet to be given to the handler.
+    failure: Option<Error>,
+}
+
+impl State {
+    #[inline]
+    fn succeed(&mut self, control: bool) -> bool {
+        self.done = control;
+        self.skip = false;
+        self.failures = 0;
+        true
+    }
+
+    #[inline]
+    fn skip(&mut self, control: bool) {
+        self.done = control;
+        self.skip = true;
+    }
+
+    /// Applies the policy of the run to the `error`. Returns `false` if the frame must stop.
+    fn fail(&mut self, error: Error, control: bool) -> bool {
+        let skip = match self.policy {
+            Policy::Abort => None,
+            Policy::Retry(count) if self.failures >= count => None,
+            Policy::Retry(_) => {
+                self.failures += 1;
+                Some(true)
+            }
+            Policy::Skip => Some(true),
+            // The state of the system may not be valid when the run that prepares it has failed.
+            Policy::Continue => Some(self.pre),
+        };
+        match skip {
+            Some(skip) => {
+                self.done = control;
+                self.skip = skip;
+                self.failure = Some(error);
+                true
+            }
+            None => {
+                self.error = Some(error);
+                false
+            }
+        }
+    }
+}
+
+/// The accesses to a `Key` by the runs that have been scheduled so far.
+#[derive(Default)]
+struct Access {
+    /// The last run that wrote to the key with a strict order.
+    writer: Option<usize>,
+    /// The runs that accessed the key since the last strict writer, with whether they wrote to it.
+    since: Vec<(usize, bool)>,
+    /// All the runs that accessed the key with a relaxed order, with whether they wrote to it.
+    relaxed: Vec<(usize, bool)>,
 }
 
 #[derive(Default)]
 struct Blockers {
+    /// Whether the run must execute on the thread that calls `Runner::run`.
+    thread: bool,
     strong: Vec<(usize, AtomicBool, Error)>,
     weak: Vec<(usize, AtomicBool)>,
 }
@@ -76,10 +206,13 @@ impl World {
             conflict.detect_inner(run.dependencies(), true).err()
         }))
         .flatten(true)
-        .map_or(Ok(()), Err)?;
+        .map_or(Ok(()), |error| {
+            Err(self.describe(error.conflict([system.name()])))
+        })?;
 
+        let state = as_mut(&mut system.state);
         for run in runs.iter_mut() {
-            run.run(&mut system.state)?;
+            run.run_exclusive(state, self)?;
         }
 
         Ok(())
@@ -92,13 +225,48 @@ impl Run {
         dependencies: impl IntoIterator<Item = Dependency>,
     ) -> Self {
         Self {
-            run: Box::new(move |state| run(state)),
+            run: Kind::Shared(Box::new(move |state| run(state))),
             dependencies: dependencies.into_iter().collect(),
+            pre: false,
         }
     }
 
+    /// Creates a run that has exclusive access to the `World`. It acts as a full barrier.
+    pub fn exclusive(
+        mut run: impl FnMut(&mut dyn Any, &mut World) -> Result + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            run: Kind::Exclusive(Box::new(move |state, world| run(state, world))),
+            dependencies: vec![Dependency::Unknown],
+            pre: false,
+        }
+    }
+
+    /// Marks the run as one that prepares the state of its system such that the runs that it blocks are skipped when
+    /// it fails, regardless of the `Policy` of the system.
+    #[inline]
+    pub(crate) fn prepare(mut self) -> Self {
+        self.pre = true;
+        self
+    }
+
+    #[inline]
+    pub const fn is_exclusive(&self) -> bool {
+        matches!(self.run, Kind::Exclusive(_))
+    }
+
     pub(crate) fn run(&mut self, state: &mut dyn Any) -> Result {
-        (self.run)(state)
+        match &mut self.run {
+            Kind::Shared(run) => run(state),
+            Kind::Exclusive(_) => Err(Error::FailedToRun),
+        }
+    }
+
+    pub(crate) fn run_exclusive(&mut self, state: &mut dyn Any, world: &mut World) -> Result {
+        match &mut self.run {
+            Kind::Shared(run) => run(state),
+            Kind::Exclusive(run) => run(state, world),
+        }
     }
 
     #[inline]
@@ -113,12 +281,60 @@ impl Runner {
         systems: I,
         world: &mut World,
     ) -> Result<Self> {
+        Self::staged(
+            parallelism,
+            [(
+                Stage::new("main", Tick::Frame),
+                systems.into_iter().collect(),
+            )],
+            world,
+        )
+    }
+
+    /// Creates a runner that executes the stages in order where each stage runs its systems as many times as its `Tick`
+    /// policy requires.
+    pub fn staged<I: IntoIterator<Item = (Stage, Vec<System>)>>(
+        parallelism: usize,
+        stages: I,
+        world: &mut World,
+    ) -> Result<Self> {
+        let mut systems = Vec::new();
+        let mut table = Vec::<Stage>::new();
+        let mut segments = Vec::new();
+        for (stage, stage_systems) in stages {
+            let start = systems.len();
+            systems.extend(stage_systems);
+            let index = match table
+                .iter()
+                .position(|other| other.identifier() == stage.identifier())
+            {
+                Some(index) => index,
+                None => {
+                    table.push(stage);
+                    table.len() - 1
+                }
+            };
+            segments.push(Segment {
+                stage: index,
+                systems: start..systems.len(),
+            });
+        }
+
         Ok(Self {
             world: world.identifier(),
             version: 0,
-            systems: systems.into_iter().collect(),
+            systems,
+            disabled: HashSet::new(),
+            retries: HashMap::new(),
+            stages: table,
+            segments,
+            time: None,
+            profiler: None,
+            mode: Mode::Parallel,
+            random: Rng::new(),
+            handler: None,
             control: false,
-            runs: vec![],
+            runs: Box::new([]),
             conflict: Conflict::default(),
             pool: ThreadPoolBuilder::new()
                 .num_threads(parallelism)
@@ -137,6 +353,186 @@ impl Runner {
         &self.systems
     }
 
+    #[inline]
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    /// Adds the `system` at the end of the last segment of the stage with the `name`. Systems with labels are rejected
+    /// since the constraints that refer to them are resolved by the `Scheduler`. The runs are rebuilt on the next
+    /// `Runner::update`.
+    pub fn insert(&mut self, name: &str, system: System) -> Result {
+        if !system.labels().is_empty() {
+            return Err(Error::ConstrainedSystem {
+                system: system.name().into(),
+            });
+        }
+        let segment = self
+            .segments
+            .iter()
+            .rposition(|segment| self.stages[segment.stage].name() == name)
+            .ok_or_else(|| Error::MissingStage { name: name.into() })?;
+        let index = self.segments[segment].systems.end;
+        for other in self.segments[segment + 1..].iter_mut() {
+            other.systems.start += 1;
+            other.systems.end += 1;
+        }
+        self.segments[segment].systems.end += 1;
+        self.systems.insert(index, system);
+        // The runs refer to the systems by index.
+        self.remember();
+        self.runs = Box::new([]);
+        self.version = 0;
+        Ok(())
+    }
+
+    /// Removes the system with the `identifier` while preserving the state of the other systems. The runs are rebuilt
+    /// on the next `Runner::update`.
+    pub fn remove(&mut self, identifier: usize) -> Option<System> {
+        let index = self
+            .systems
+            .iter()
+            .position(|system| system.identifier() == identifier)?;
+        for segment in self.segments.iter_mut() {
+            if segment.systems.start > index {
+                segment.systems.start -= 1;
+            }
+            if segment.systems.end > index {
+                segment.systems.end -= 1;
+            }
+        }
+        self.disabled.remove(&identifier);
+        // The runs refer to the systems by index.
+        self.remember();
+        self.runs = Box::new([]);
+        self.version = 0;
+        Some(self.systems.remove(index))
+    }
+
+    /// Enables the systems with the name or the label `target` and returns how many matched.
+    pub fn enable(&mut self, target: &str) -> usize {
+        self.toggle(target, true)
+    }
+
+    /// Disables the systems with the name or the label `target` and returns how many matched. Disabled systems keep
+    /// their state but produce no runs.
+    pub fn disable(&mut self, target: &str) -> usize {
+        self.toggle(target, false)
+    }
+
+    #[inline]
+    pub fn is_enabled(&self, identifier: usize) -> bool {
+        !self.disabled.contains(&identifier)
+    }
+
+    fn toggle(&mut self, target: &str, enable: bool) -> usize {
+        let mut count = 0;
+        for system in self.systems.iter() {
+            if system.name() == target || system.labels().iter().any(|label| label == target) {
+                let identifier = system.identifier();
+                let change = if enable {
+                    self.disabled.remove(&identifier)
+                } else {
+                    self.disabled.insert(identifier)
+                };
+                if change {
+                    self.version = 0;
+                }
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Enables or disables the recording of the timing of runs. Enabling it discards previously recorded events.
+    pub fn profile(&mut self, enable: bool) {
+        self.profiler = if enable {
+            let mut profiler = Profiler::new(self.pool.current_num_threads());
+            profiler.names.extend(Self::names(&self.systems));
+            Some(profiler)
+        } else {
+            None
+        };
+    }
+
+    #[inline]
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Changes how the runs are executed. A `Mode::Shuffle` restarts its sequence of orders from its seed.
+    pub fn set_mode(&mut self, mode: Mode) {
+        if let Mode::Shuffle(seed) = mode {
+            self.random = Rng::with_seed(seed);
+        }
+        self.mode = mode;
+    }
+
+    /// Sets the `handler` that receives the name of the system and the error of every failed run, regardless of the
+    /// `Policy` of the system.
+    pub fn handle<H: FnMut(&str, &Error) + Send + Sync + 'static>(&mut self, handler: H) {
+        self.handler = Some(Box::new(handler));
+    }
+
+    #[inline]
+    pub fn profiler(&self) -> Option<&Profiler> {
+        self.profiler.as_ref()
+    }
+
+    #[inline]
+    pub fn profiler_mut(&mut self) -> Option<&mut Profiler> {
+        self.profiler.as_mut()
+    }
+
+    fn names(systems: &[System]) -> impl Iterator<Item = (usize, String)> + '_ {
+        systems
+            .iter()
+            .map(|system| (system.identifier(), system.name().into()))
+    }
+
+    /// Returns the runs and their blockers as of the last `Runner::update`.
+    pub fn graph(&self) -> Graph {
+        let mut nodes = Vec::with_capacity(self.runs.len());
+        for (index, (run, blockers)) in self.runs.iter().enumerate() {
+            let (run, state) = &*run.read();
+            let stage = &self.stages[self.segments[state.segment].stage];
+            let system = if state.barrier {
+                format!("{}::barrier", stage.name())
+            } else {
+                self.systems[state.system].name().into()
+            };
+            let strong = blockers
+                .strong
+                .iter()
+                .map(|(blocker, _, error)| Edge {
+                    blocker: *blocker,
+                    errors: graph::errors(error.clone()),
+                })
+                .collect();
+            let weak = blockers
+                .weak
+                .iter()
+                .map(|(blocker, _)| Edge {
+                    blocker: *blocker,
+                    errors: graph::relaxed(
+                        run.dependencies(),
+                        self.runs[*blocker].0.read().0.dependencies(),
+                    ),
+                })
+                .collect();
+            nodes.push(Node {
+                index,
+                system,
+                stage: stage.name().into(),
+                exclusive: run.is_exclusive(),
+                dependencies: run.dependencies().to_vec(),
+                strong,
+                weak,
+            });
+        }
+        Graph { nodes }
+    }
+
     pub fn update(&mut self, world: &mut World) -> Result<bool> {
         if self.world != world.identifier() {
             return Err(Error::WrongWorld {
@@ -151,25 +547,75 @@ impl Runner {
         // 'I::schedule' may cause more changes of the 'world.version()'. Loop until the version has stabilized.
         for _ in 0..1_000 {
             if version.change(world.version()) {
-                self.runs = self
-                    .systems
-                    .iter_mut()
-                    .flat_map(|system| {
-                        system.schedule(world).into_iter().map(|run| {
-                            (
-                                RwLock::new((
-                                    run,
-                                    State {
-                                        state: system.state.clone(),
-                                        done: self.control,
-                                        error: None,
-                                    },
-                                )),
-                                Blockers::default(),
-                            )
-                        })
-                    })
-                    .collect();
+                self.remember();
+                let retries = take(&mut self.retries);
+                let control = self.control;
+                let mut runs = Vec::new();
+                let mut push =
+                    |run: Run, system, identifier, segment, barrier, policy, state, failures| {
+                        let thread = run.dependencies().contains(&Dependency::Thread);
+                        let pre = run.pre;
+                        runs.push((
+                            RwLock::new((
+                                run,
+                                State {
+                                    system,
+                                    identifier,
+                                    segment,
+                                    barrier,
+                                    pre,
+                                    state,
+                                    policy,
+                                    done: control,
+                                    skip: false,
+                                    failures,
+                                    error: None,
+                                    failure: None,
+                                },
+                            )),
+                            Blockers {
+                                thread,
+                                ..Default::default()
+                            },
+                        ));
+                    };
+
+                for (segment, Segment { systems, .. }) in self.segments.iter().enumerate() {
+                    if segment > 0 {
+                        // Stage boundaries are explicit synchronization points.
+                        let barrier = Run::new(|_| Ok(()), [Dependency::Unknown]);
+                        let policy = Policy::Abort;
+                        let state = Arc::new(());
+                        push(barrier, systems.start, 0, segment, true, policy, state, 0);
+                    }
+                    for system in systems.clone() {
+                        let identifier = self.systems[system].identifier();
+                        if self.disabled.contains(&identifier) {
+                            continue;
+                        }
+                        let state = self.systems[system].state.clone();
+                        let policy = self.systems[system].policy();
+                        let runs = self.systems[system].schedule(world);
+                        for (position, run) in runs.into_iter().enumerate() {
+                            let state = state.clone();
+                            let failures = retries.get(&(identifier, position)).copied();
+                            push(
+                                run,
+                                system,
+                                identifier,
+                                segment,
+                                false,
+                                policy,
+                                state,
+                                failures.unwrap_or_default(),
+                            );
+                        }
+                    }
+                }
+                self.runs = runs.into();
+                if let Some(profiler) = &mut self.profiler {
+                    profiler.names.extend(Self::names(&self.systems));
+                }
             } else {
                 break;
             }
@@ -179,41 +625,182 @@ impl Runner {
             return Err(Error::UnstableWorldVersion);
         }
 
-        self.schedule()?;
+        self.schedule().map_err(|error| world.describe(error))?;
 
         // Only commit the new version and restore `success` if scheduling and dependency analysis succeed.
         self.version = version;
         Ok(true)
     }
 
+    /// Runs a frame where the elapsed time is measured since the previous frame.
     pub fn run(&mut self, world: &mut World) -> Result {
+        let now = Instant::now();
+        let delta = self
+            .time
+            .replace(now)
+            .map_or(Duration::ZERO, |time| now - time);
+        self.run_for(world, delta)
+    }
+
+    /// Runs a frame that lasted `delta`.
+    pub fn run_for(&mut self, world: &mut World, delta: Duration) -> Result {
         self.update(world)?;
+        if let Some(frame) = world.resources().get_mut::<Frame>() {
+            frame.0 += 1;
+        }
+        message::deliver(world);
+        self.control = self.control.not();
+        if let Some(profiler) = &mut self.profiler {
+            profiler.next();
+        }
 
-        let Self {
-            control,
-            runs,
-            pool,
-            ..
-        } = self;
+        // The segments of a stage that resumes after an inner stage run as many times as its first segment.
+        let mut counts = vec![None; self.stages.len()];
+        for segment in 0..self.segments.len() {
+            let stage = self.segments[segment].stage;
+            let ticks = *counts[stage].get_or_insert_with(|| self.stages[stage].ticks(delta));
+            if ticks == 0 {
+                let (start, end) = self.bounds(segment);
+                self.reset(start, end, self.control);
+            }
 
-        *control = control.not();
+            for tick in 0..ticks {
+                if tick > 0 {
+                    let (start, end) = self.bounds(segment);
+                    self.reset(start, end, self.control.not());
+                }
 
-        let index = AtomicUsize::new(0);
-        let mut success = AtomicBool::new(true);
-        let control = *control;
-        pool.scope(|scope| {
-            for _ in 0..pool.current_num_threads() {
-                scope.spawn(|_| {
-                    success.fetch_and(Self::progress(&index, runs, control), Ordering::Relaxed);
-                });
+                if let Err(error) = self.segment(world, segment) {
+                    // Leave no run in a state that could be mistaken as done in the next frame.
+                    self.reset(0, self.runs.len(), self.control);
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn segment(&mut self, world: &mut World, segment: usize) -> Result {
+        let (mut start, _) = self.bounds(segment);
+        loop {
+            // Runs are executed in parallel phases that are separated by exclusive runs.
+            let (_, end) = self.bounds(segment);
+            let exclusive = self.runs[start..end]
+                .iter_mut()
+                .position(|(run, _)| run.get_mut().0.is_exclusive())
+                .map_or(end, |index| start + index);
+            self.phase(start, exclusive)?;
+
+            let control = self.control;
+            let system = match self.runs[..end].get_mut(exclusive) {
+                Some((run, _)) => {
+                    let (run, state) = run.get_mut();
+                    let input = as_mut(&mut state.state);
+                    let time = self.profiler.as_ref().map(|_| Instant::now());
+                    let result = run.run_exclusive(input, world);
+                    if let (Some(profiler), Some(time)) = (&self.profiler, time) {
+                        profiler.record(Event::Run, owner(state), time, Instant::now());
+                    }
+                    let success = match result {
+                        Ok(_) => state.succeed(control),
+                        Err(error) => state.fail(error, control),
+                    };
+                    let system = state.system;
+                    self.report(exclusive, exclusive + 1);
+                    if !success {
+                        let state = &mut self.runs[exclusive].0.get_mut().1;
+                        return Err(state.error.take().unwrap_or(Error::FailedToRun));
+                    }
+                    system
+                }
+                None => break Ok(()),
+            };
+
+            start = exclusive + 1;
+            if self.version != world.version() {
+                // The exclusive run modified the world. The runs are rebuilt as they would have been before this frame
+                // and the ones that belong to systems that already executed are marked as done.
+                self.control = control.not();
+                self.update(world)?;
+                self.control = control;
+
+                start = self.runs.len();
+                for (index, (run, _)) in self.runs.iter_mut().enumerate() {
+                    let state = &mut run.get_mut().1;
+                    if state.system <= system {
+                        state.done = control;
+                    } else {
+                        start = start.min(index);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Remembers the runs that failed under `Policy::Retry` such that they keep their state when the runs are rebuilt.
+    fn remember(&mut self) {
+        let mut starts = HashMap::new();
+        for (index, (run, _)) in self.runs.iter_mut().enumerate() {
+            let state = &run.get_mut().1;
+            if state.barrier {
+                continue;
+            }
+            let start = *starts.entry(state.identifier).or_insert(index);
+            if state.failures > 0 {
+                self.retries
+                    .insert((state.identifier, index - start), state.failures);
+            }
+        }
+    }
+
+    /// Returns the range of the runs that belong to the `segment`.
+    fn bounds(&mut self, segment: usize) -> (usize, usize) {
+        let mut start = None;
+        let mut end = self.runs.len();
+        for (index, (run, _)) in self.runs.iter_mut().enumerate() {
+            let current = run.get_mut().1.segment;
+            if current == segment && start.is_none() {
+                start = Some(index);
+            } else if current > segment {
+                end = index;
+                break;
             }
-        });
+        }
+        (start.unwrap_or(end), end)
+    }
 
-        if success.into_inner() {
+    /// Sets the `done` state of the runs in the range and of their cached blockers.
+    fn reset(&mut self, start: usize, end: usize, done: bool) {
+        for (run, blockers) in self.runs[start..end].iter_mut() {
+            let state = &mut run.get_mut().1;
+            state.done = done;
+            state.skip = false;
+            for (_, blocker, _) in blockers.strong.iter_mut() {
+                *blocker.get_mut() = done;
+            }
+            for (_, blocker) in blockers.weak.iter_mut() {
+                *blocker.get_mut() = done;
+            }
+        }
+    }
+
+    fn phase(&mut self, start: usize, end: usize) -> Result {
+        let success = match self.mode {
+            Mode::Parallel => self.parallel(start, end),
+            Mode::Sequential => self.sequence((start..end).collect()),
+            Mode::Shuffle(_) => {
+                let order = self.shuffle(start, end);
+                self.sequence(order)
+            }
+        };
+        self.report(start, end);
+
+        if success {
             Ok(())
         } else {
             Error::all(
-                runs.iter_mut()
+                self.runs[start..end]
+                    .iter_mut()
                     .filter_map(|(run, _)| run.get_mut().1.error.take()),
             )
             .flatten(true)
@@ -221,105 +808,150 @@ impl Runner {
         }
     }
 
-    /// The synchronization mechanism is in 2 parts:
-    /// 1. An `AtomicUsize` is used to reserve an index in the `runs` vector. It ensures that each run is executed only once.
-    /// 2. A `Mutex` around the run and its state that will force the blocked threads to wait until this run is done. This choice
-    /// of synchronization prevents sneaky interleaving of threads and is very straightforward to implement.
-    /// - This mechanism can not produce a dead lock as long as the `blockers` are all indices `< index` (which they are by design).
-    /// Since runs are executed in order, for any index that is reserved, all indices smaller than that index represent a run that
-    /// is done or in progress (not idle) which is important to prevent a spin loop when waiting for `blockers` to finish.
-    /// - This mechanism has a lookahead that is equal to the degree of parallelism which is currently the number of logical CPUs by default.
-    fn progress(index: &AtomicUsize, runs: &Runs, control: bool) -> bool {
-        fn progress(index: usize, runs: &Runs, control: bool, lock: bool) -> Option<bool> {
-            let (run, blockers) = match runs.get(index) {
-                Some(run) => run,
-                None => return Some(true),
-            };
-
-            match if lock {
-                Some(run.read())
-            } else {
-                run.try_read()
-            } {
-                Some(guard) if guard.1.done == control => return Some(true),
-                Some(guard) if guard.1.error.is_some() => return None,
-                _ => {}
+    /// Gives the errors of the runs in the range to the handler.
+    fn report(&mut self, start: usize, end: usize) {
+        let Self {
+            runs,
+            systems,
+            handler,
+            ..
+        } = self;
+        for (run, _) in runs[start..end].iter_mut() {
+            let state = &mut run.get_mut().1;
+            let failure = state.failure.take();
+            if let (Some(handler), Some(error)) = (&mut *handler, failure.or(state.error.clone())) {
+                let name = systems.get(state.system).map_or("", System::name);
+                handler(name, &error);
             }
+        }
+    }
 
-            let mut ready = true;
-            for (blocker, done, _) in blockers.strong.iter() {
-                debug_assert!(*blocker < index);
+    /// Executes the runs in the range on the thread pool and returns `false` if a run stopped the frame.
+    fn parallel(&mut self, start: usize, end: usize) -> bool {
+        let Self {
+            control,
+            runs,
+            pool,
+            profiler,
+            ..
+        } = self;
 
-                if done.load(Ordering::Acquire) == control {
-                    continue;
+        let index = AtomicUsize::new(start);
+        let success = AtomicBool::new(true);
+        let control = *control;
+        let profiler = profiler.as_ref();
+        let runs = &mut runs[..end];
+        {
+            let runs = &*runs;
+            // The scope runs on the calling thread such that thread-bound runs can execute on it while the other runs
+            // continue on the pool.
+            pool.in_place_scope(|scope| {
+                for _ in 0..pool.current_num_threads() {
+                    scope.spawn(|_| {
+                        success.fetch_and(
+                            Self::progress(&index, runs, control, profiler),
+                            Ordering::Relaxed,
+                        );
+                    });
                 }
+                success.fetch_and(
+                    Self::progress_thread(runs, start, control, &success, profiler),
+                    Ordering::Relaxed,
+                );
+            });
+        }
 
-                // Do not try to `progress` here since if `blocker < index`, it is expected that the blocker index will be
-                // locked by its responsible thread imminently. So this lock should be kept for the least amount of time.
-                match if lock {
-                    Some(runs[*blocker].0.read())
-                } else {
-                    runs[*blocker].0.try_read()
-                } {
-                    Some(guard) if guard.1.done == control => {
-                        done.store(control, Ordering::Release)
-                    }
-                    Some(guard) if guard.1.error.is_some() => return None,
-                    Some(_) | None => ready = false,
-                };
-            }
+        success.into_inner()
+    }
 
-            let mut guards = Vec::new();
-            for (blocker, done) in blockers.weak.iter() {
-                if done.load(Ordering::Acquire) == control {
-                    continue;
-                }
+    /// Executes the runs on the calling thread in the given `order` which must respect their strong blockers. Returns
+    /// `false` if a run stopped the frame.
+    fn sequence(&mut self, order: Vec<usize>) -> bool {
+        let control = self.control;
+        for index in order {
+            let skip = self.runs[index].1.strong.iter().any(|&(blocker, _, _)| {
+                let state = &self.runs[blocker].0.read().1;
+                state.done == control && state.skip
+            });
+            let ((run, state), blockers) = {
+                let (run, blockers) = &mut self.runs[index];
+                (run.get_mut(), blockers)
+            };
+            // Keep the cached blockers consistent in case the mode changes.
+            for (_, done, _) in blockers.strong.iter_mut() {
+                *done.get_mut() = control;
+            }
+            for (_, done) in blockers.weak.iter_mut() {
+                *done.get_mut() = control;
+            }
+            if state.done == control {
+                continue;
+            } else if skip {
+                state.skip(control);
+                continue;
+            }
 
-                match runs[*blocker].0.try_read() {
-                    Some(guard) if guard.1.done => done.store(control, Ordering::Release),
-                    Some(guard) if guard.1.error.is_some() => return None,
-                    Some(guard) if ready => guards.push(guard),
-                    guard => {
-                        drop(guard);
-                        if guards.len() > 0 {
-                            guards.clear();
-                            ready = false;
-                        } else if progress(*blocker, runs, control, false)? {
-                            done.store(control, Ordering::Release);
-                        } else {
-                            ready = false;
-                        }
-                    }
-                };
+            let input = as_mut(&mut state.state);
+            let time = self.profiler.as_ref().map(|_| Instant::now());
+            let result = run.run(input);
+            if let (Some(profiler), Some(time)) = (&self.profiler, time) {
+                profiler.record(Event::Run, owner(state), time, Instant::now());
+            }
+            let success = match result {
+                Ok(_) => state.succeed(control),
+                Err(error) => state.fail(error, control),
+            };
+            if !success {
+                return false;
             }
+        }
+        true
+    }
 
-            if ready {
-                let guard = run.upgradable_read();
-                if guard.1.done == control {
-                    return Some(true);
-                } else if guard.1.error.is_some() {
-                    return None;
+    /// Picks a random topological order of the runs in the range. Blockers outside of the range are already done.
+    fn shuffle(&mut self, start: usize, end: usize) -> Vec<usize> {
+        let mut counts = vec![0; end - start];
+        let mut blocks = vec![Vec::new(); end - start];
+        for (index, (_, blockers)) in self.runs[start..end].iter().enumerate() {
+            for &(blocker, _, _) in blockers.strong.iter() {
+                if blocker >= start {
+                    counts[index] += 1;
+                    blocks[blocker - start].push(index);
                 }
+            }
+        }
 
-                let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
-                let input = as_mut(&mut guard.1.state);
-                let result = guard.0.run(input);
-                guards.clear();
-                match result {
-                    Ok(_) => {
-                        guard.1.done = control;
-                        Some(true)
-                    }
-                    Err(error) => {
-                        guard.1.error = Some(error);
-                        None
-                    }
+        let mut ready: Vec<_> = (0..counts.len())
+            .filter(|&index| counts[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(counts.len());
+        while !ready.is_empty() {
+            let index = ready.swap_remove(self.random.usize(..ready.len()));
+            order.push(start + index);
+            for &next in blocks[index].iter() {
+                counts[next] -= 1;
+                if counts[next] == 0 {
+                    ready.push(next);
                 }
-            } else {
-                Some(false)
             }
         }
+        order
+    }
 
+    /// The synchronization mechanism is in 2 parts:
+    /// 1. An `AtomicUsize` is used to reserve an index in the `runs` vector. It ensures that each run is executed only once.
+    /// 2. A `Mutex` around the run and its state that will force the blocked threads to wait until this run is done. This choice
+    ///    of synchronization prevents sneaky interleaving of threads and is very straightforward to implement.
+    /// - This mechanism can not produce a dead lock as long as the `blockers` are all indices `< index` (which they are by design).
+    ///   Since runs are executed in order, for any index that is reserved, all indices smaller than that index represent a run that
+    ///   is done or in progress (not idle) which is important to prevent a spin loop when waiting for `blockers` to finish.
+    /// - This mechanism has a lookahead that is equal to the degree of parallelism which is currently the number of logical CPUs by default.
+    fn progress(
+        index: &AtomicUsize,
+        runs: &Runs,
+        control: bool,
+        profiler: Option<&Profiler>,
+    ) -> bool {
         loop {
             // `Ordering` doesn't matter here, only atomicity.
             let index = index.fetch_add(1, Ordering::Relaxed);
@@ -332,15 +964,26 @@ impl Runner {
             //     None => return true,
             // };
 
-            match progress(index, &runs, control, false) {
+            match runs.get(index) {
+                // Runs that are bound to the calling thread are left to `Self::progress_thread`.
+                Some((_, blockers)) if blockers.thread => continue,
+                Some(_) => {}
+                None => return true,
+            }
+
+            match progress(index, runs, control, false, false, profiler) {
                 Some(true) => continue,
-                Some(false) => loop {
-                    match progress(index, &runs, control, true) {
-                        Some(true) => break,
-                        Some(false) => yield_now(),
-                        None => return false,
+                Some(false) => {
+                    let time = Instant::now();
+                    loop {
+                        let now = Instant::now();
+                        match progress(index, runs, control, true, false, profiler) {
+                            Some(true) => break wait(index, runs, profiler, time, now),
+                            Some(false) => yield_now(),
+                            None => return false,
+                        }
                     }
-                },
+                }
                 None => return false,
             }
 
@@ -382,6 +1025,31 @@ impl Runner {
         }
     }
 
+    /// Executes, in order, the runs that are bound to the calling thread. Since all other runs are executed by the pool
+    /// (and in order), the blockers of these runs are guaranteed to be reserved by a pool thread unless it has failed.
+    fn progress_thread(
+        runs: &Runs,
+        start: usize,
+        control: bool,
+        success: &AtomicBool,
+        profiler: Option<&Profiler>,
+    ) -> bool {
+        for (index, (_, blockers)) in runs.iter().enumerate().skip(start) {
+            if blockers.thread {
+                let time = Instant::now();
+                loop {
+                    let now = Instant::now();
+                    match progress(index, runs, control, true, true, profiler) {
+                        Some(true) => break wait(index, runs, profiler, time, now),
+                        Some(false) if success.load(Ordering::Relaxed) => yield_now(),
+                        Some(false) | None => return false,
+                    }
+                }
+            }
+        }
+        true
+    }
+
     /// Remove transitive pre blockers.
     fn refine_strong_blockers(&mut self) {
         let mut runs = &mut self.runs[..];
@@ -398,7 +1066,7 @@ impl Runner {
         }
     }
 
-    /// Removes the post blockers that have pre blockers later than the current run.
+    // Removes the post blockers that have pre blockers later than the current run.
     // fn refine_weak_blockers(&mut self) {
     //     let mut runs = &mut self.runs[..];
     //     let mut index = 0;
@@ -415,28 +1083,74 @@ impl Runner {
     //     }
     // }
 
+    /// Blockers are found through an index of the accesses to each `Key` such that a run is only compared with the last
+    /// strict writer of its keys, the accesses that followed it and the relaxed accesses (since those may produce weak
+    /// blockers). Earlier accesses are already ordered before the last strict writer, so the resulting strong blockers
+    /// produce the same ordering as comparing all pairs of runs.
     fn schedule(&mut self) -> Result {
-        // TODO: This algorithm scales poorly (n^2 / 2, where n is the number of runs) and a lot of the work is redundant
-        // as shown by the refining part. This could be optimized.
-        let mut runs = &mut self.runs[..];
-        while let Some((tail, rest)) = runs.split_last_mut() {
-            let (run, _) = tail.0.get_mut();
-            self.conflict.detect_inner(&run.dependencies, true)?;
-
-            let index = rest.len();
-            for (i, rest) in rest.iter_mut().enumerate() {
-                let pair = rest.0.get_mut();
-                match self.conflict.detect_outer(&pair.0.dependencies, true) {
+        let mut accesses = HashMap::<Key, Access>::new();
+        let mut unknown = None;
+        let mut marks = vec![usize::MAX; self.runs.len()];
+        let mut candidates = Vec::new();
+
+        for index in 0..self.runs.len() {
+            let (rest, tail) = self.runs.split_at_mut(index);
+            let (lock, blockers) = &mut tail[0];
+            let (run, state) = lock.get_mut();
+            if let Err(error) = self.conflict.detect_inner(&run.dependencies, true) {
+                let name = self.systems.get(state.system).map_or("", System::name);
+                return Err(error.conflict([name]));
+            }
+
+            // A run with an unknown dependency blocks all later runs, including the previous unknown ones.
+            candidates.extend(unknown);
+            for dependency in run.dependencies.iter() {
+                let (key, write) = match *dependency {
+                    Dependency::Read(key, _) => (key, false),
+                    Dependency::Write(key, _) => (key, true),
+                    Dependency::Unknown | Dependency::Thread => continue,
+                };
+                if let Some(access) = accesses.get(&key) {
+                    candidates.extend(access.writer);
+                    // Reads only conflict with writes.
+                    let conflicts = access.since.iter().chain(access.relaxed.iter());
+                    candidates.extend(conflicts.filter(|pair| write || pair.1).map(|pair| pair.0));
+                }
+            }
+            candidates.retain(|&blocker| replace(&mut marks[blocker], index) != index);
+            candidates.sort_unstable();
+
+            for blocker in candidates.drain(..) {
+                let (pair, pair_blockers) = &mut rest[blocker];
+                match self
+                    .conflict
+                    .detect_outer(&pair.get_mut().0.dependencies, true)
+                {
                     Ok(Order::Strict) => {}
                     Ok(Order::Relax) => {
-                        tail.1.weak.push((i, self.control.into()));
-                        rest.1.weak.push((index, self.control.into()));
+                        blockers.weak.push((blocker, self.control.into()));
+                        pair_blockers.weak.push((index, self.control.into()));
                     }
-                    Err(error) => tail.1.strong.push((i, self.control.into(), error.into())),
+                    Err(error) => blockers.strong.push((blocker, self.control.into(), error)),
                 }
             }
 
-            runs = rest;
+            for dependency in run.dependencies.iter() {
+                match *dependency {
+                    Dependency::Unknown => unknown = Some(index),
+                    Dependency::Thread => {}
+                    Dependency::Write(key, Order::Strict) => {
+                        let access = accesses.entry(key).or_default();
+                        access.writer = Some(index);
+                        access.since.clear();
+                    }
+                    Dependency::Read(key, order) | Dependency::Write(key, order) => {
+                        let write = matches!(dependency, Dependency::Write(..));
+                        let access = accesses.entry(key).or_default();
+                        access.push(index, write, order);
+                    }
+                }
+            }
         }
 
         self.refine_strong_blockers();
@@ -445,7 +1159,155 @@ impl Runner {
     }
 }
 
+impl Access {
+    fn push(&mut self, index: usize, write: bool, order: Order) {
+        if self.since.last().is_none_or(|pair| pair.0 != index) {
+            self.since.push((index, write));
+        }
+        if order == Order::Relax && self.relaxed.last().is_none_or(|pair| pair.0 != index) {
+            self.relaxed.push((index, write));
+        }
+    }
+}
+
+/// Progresses the run at `index`. Runs that are bound to the calling thread may only execute when `thread` is `true`.
+fn progress(
+    index: usize,
+    runs: &Runs,
+    control: bool,
+    lock: bool,
+    thread: bool,
+    profiler: Option<&Profiler>,
+) -> Option<bool> {
+    let (run, blockers) = match runs.get(index) {
+        Some(run) => run,
+        None => return Some(true),
+    };
+
+    match if lock {
+        Some(run.read())
+    } else {
+        run.try_read()
+    } {
+        Some(guard) if guard.1.done == control => return Some(true),
+        Some(guard) if guard.1.error.is_some() => return None,
+        _ => {}
+    }
+
+    let mut ready = true;
+    let mut skip = false;
+    for (blocker, done, _) in blockers.strong.iter() {
+        debug_assert!(*blocker < index);
+
+        if done.load(Ordering::Acquire) == control {
+            continue;
+        }
+
+        // Do not try to `progress` here since if `blocker < index`, it is expected that the blocker index will be
+        // locked by its responsible thread imminently. So this lock should be kept for the least amount of time.
+        match if lock {
+            Some(runs[*blocker].0.read())
+        } else {
+            runs[*blocker].0.try_read()
+        } {
+            Some(guard) if guard.1.done == control && guard.1.skip => {
+                done.store(control, Ordering::Release);
+                skip = true;
+            }
+            Some(guard) if guard.1.done == control => done.store(control, Ordering::Release),
+            Some(guard) if guard.1.error.is_some() => return None,
+            Some(_) | None => ready = false,
+        };
+    }
+
+    if skip {
+        if !ready {
+            // The runs that this one blocks must not start before its other strong blockers are done.
+            return Some(false);
+        }
+        let mut guard = run.write();
+        if guard.1.done != control {
+            guard.1.skip(control);
+        }
+        return Some(true);
+    }
+
+    let mut guards = Vec::new();
+    for (blocker, done) in blockers.weak.iter() {
+        if done.load(Ordering::Acquire) == control {
+            continue;
+        }
+
+        match runs[*blocker].0.try_read() {
+            Some(guard) if guard.1.done == control => done.store(control, Ordering::Release),
+            Some(guard) if guard.1.error.is_some() => return None,
+            Some(guard) if ready => guards.push(guard),
+            guard => {
+                drop(guard);
+                if !guards.is_empty() {
+                    guards.clear();
+                    ready = false;
+                } else if progress(*blocker, runs, control, false, thread, profiler)? {
+                    done.store(control, Ordering::Release);
+                } else {
+                    ready = false;
+                }
+            }
+        };
+    }
+
+    if ready && blockers.thread && !thread {
+        Some(false)
+    } else if ready {
+        let guard = run.upgradable_read();
+        if guard.1.done == control {
+            return Some(true);
+        } else if guard.1.error.is_some() {
+            return None;
+        }
+
+        let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+        let input = as_mut(&mut guard.1.state);
+        let time = profiler.map(|_| Instant::now());
+        let result = guard.0.run(input);
+        guards.clear();
+        if let (Some(profiler), Some(time)) = (profiler, time) {
+            profiler.record(Event::Run, owner(&guard.1), time, Instant::now());
+        }
+        let success = match result {
+            Ok(_) => guard.1.succeed(control),
+            Err(error) => guard.1.fail(error, control),
+        };
+        if success {
+            Some(true)
+        } else {
+            None
+        }
+    } else {
+        Some(false)
+    }
+}
+
+#[inline]
+fn owner(state: &State) -> Option<usize> {
+    if state.barrier {
+        None
+    } else {
+        Some(state.identifier)
+    }
+}
+
+/// Records the time that a thread spent waiting for the blockers of the run at `index`.
+fn wait(index: usize, runs: &Runs, profiler: Option<&Profiler>, start: Instant, end: Instant) {
+    if let Some(profiler) = profiler {
+        if end > start {
+            let system = owner(&runs[index].0.read().1);
+            profiler.record(Event::Wait, system, start, end);
+        }
+    }
+}
+
 #[inline]
 pub(crate) fn as_mut<'a, T: ?Sized>(state: &mut Arc<T>) -> &'a mut T {
-    unsafe { &mut *(Arc::as_ptr(&state) as *mut T) }
+    unsafe { &mut *(Arc::as_ptr(state) as *mut T) }
 }
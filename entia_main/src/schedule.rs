@@ -1,23 +1,42 @@
 use crate::{
     error::{Error, Result},
     run::Runner,
-    system::{IntoSystem, System},
+    stage::{Stage, Tick},
+    system::{If, IntoSystem, Policy, System},
     world::World,
 };
 use entia_core::utility::short_type_name;
-use std::any::type_name;
+use std::{
+    any::type_name,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    ops::Range,
+};
 
 pub struct Scheduler<'a> {
     prefix: String,
-    systems: Vec<Result<System>>,
+    systems: Vec<(Result<System>, Constraint)>,
+    /// The systems that were added by the last call to `add`, `pipe`, `set` or `stage`.
+    last: Range<usize>,
+    /// Each stage holds the index of its first system.
+    stages: Vec<(Stage, usize)>,
     world: &'a mut World,
 }
 
+#[derive(Default)]
+struct Constraint {
+    labels: Vec<String>,
+    before: Vec<String>,
+    after: Vec<String>,
+}
+
 impl World {
-    pub fn scheduler(&mut self) -> Scheduler {
+    pub fn scheduler(&mut self) -> Scheduler<'_> {
         Scheduler {
             prefix: String::new(),
             systems: Vec::new(),
+            last: 0..0,
+            stages: vec![(Stage::new("main", Tick::Frame), 0)],
             world: self,
         }
     }
@@ -25,9 +44,60 @@ impl World {
 
 impl Scheduler<'_> {
     pub fn pipe<F: FnOnce(Self) -> Self>(self, schedule: F) -> Self {
-        self.with_prefix::<F, _>(schedule)
+        self.group(|scheduler| scheduler.with_prefix::<F, _>(schedule))
+    }
+
+    /// Adds the systems of the `schedule` to a set that is identified by the `label`. Constraints that follow apply to
+    /// all the systems of the set.
+    pub fn set<F: FnOnce(Self) -> Self>(self, label: &str, schedule: F) -> Self {
+        self.group(schedule).label(label)
+    }
+
+    /// Labels the systems that were last added such that other systems may be ordered relative to them.
+    pub fn label(mut self, label: &str) -> Self {
+        self.constrain(|constraint| constraint.labels.push(label.into()));
+        self
     }
 
+    /// Orders the systems that were last added before the systems with the `label`. Systems with the `label` that belong
+    /// to other stages must belong to later ones.
+    pub fn before(mut self, label: &str) -> Self {
+        self.constrain(|constraint| constraint.before.push(label.into()));
+        self
+    }
+
+    /// Orders the systems that were last added after the systems with the `label`. Systems with the `label` that belong
+    /// to other stages must belong to earlier ones.
+    pub fn after(mut self, label: &str) -> Self {
+        self.constrain(|constraint| constraint.after.push(label.into()));
+        self
+    }
+
+    /// Sets what happens when a run of the systems that were last added fails.
+    pub fn policy(mut self, policy: Policy) -> Self {
+        for (system, _) in self.systems[self.last.clone()].iter_mut() {
+            if let Ok(system) = system {
+                system.policy = policy;
+            }
+        }
+        self
+    }
+
+    /// Adds the systems of the `schedule` to a new stage that runs according to the `tick` policy. Stages run in the
+    /// order in which they are declared and the boundaries between them are synchronization points.
+    pub fn stage<F: FnOnce(Self) -> Self>(mut self, name: &str, tick: Tick, schedule: F) -> Self {
+        let outer = match self.stages.last() {
+            Some((stage, _)) => stage.clone(),
+            None => Stage::new("main", Tick::Frame),
+        };
+        self.stages
+            .push((Stage::new(name, tick), self.systems.len()));
+        self = self.group(schedule);
+        self.stages.push((outer, self.systems.len()));
+        self
+    }
+
+    #[allow(clippy::should_implement_trait)]
     pub fn add<M, S: IntoSystem<M>>(self, system: S) -> Self
     where
         S::Input: Default,
@@ -35,13 +105,24 @@ impl Scheduler<'_> {
         self.add_with(S::Input::default(), system)
     }
 
+    /// Adds the `system` such that it only runs when the `condition` returns `true`.
+    pub fn add_if<M, C, S>(self, condition: C, system: S) -> Self
+    where
+        If<C, S>: IntoSystem<M>,
+        <If<C, S> as IntoSystem<M>>::Input: Default,
+    {
+        self.add(If(condition, system))
+    }
+
     pub fn add_with<M, S: IntoSystem<M>>(self, input: S::Input, system: S) -> Self {
         self.with_prefix::<S, _>(|mut scheduler| {
             let system = system.system(input, scheduler.world).map(|mut system| {
                 system.name.insert_str(0, &scheduler.prefix);
                 system
             });
-            scheduler.systems.push(system);
+            let index = scheduler.systems.len();
+            scheduler.systems.push((system, Constraint::default()));
+            scheduler.last = index..index + 1;
             scheduler
         })
     }
@@ -51,19 +132,78 @@ impl Scheduler<'_> {
     }
 
     pub fn schedule_with(self, parallelism: usize) -> Result<Runner> {
-        let mut schedules = Vec::new();
+        let mut stages = Vec::new();
         let mut errors = Vec::new();
+        let mut labels = HashSet::new();
+        let mut bounds = self.stages.into_iter().peekable();
+        for (index, (schedule, constraint)) in self.systems.into_iter().enumerate() {
+            while let Some((stage, _)) = bounds.next_if(|&(_, start)| start <= index) {
+                stages.push((stage, Vec::new()));
+            }
+
+            labels.extend(constraint.labels.iter().cloned());
+            match (schedule, stages.last_mut()) {
+                (Ok(mut system), Some((_, systems))) => {
+                    system.labels = constraint.labels.clone();
+                    systems.push((system, constraint))
+                }
+                (Ok(_), None) => errors.push(Error::FailedToSchedule),
+                (Err(error), _) => errors.push(error),
+            }
+        }
 
-        for schedule in self.systems {
-            match schedule {
-                Ok(system) => schedules.push(system),
+        // Constraints on labels that belong to other stages are satisfied by the order of the stages if they agree with it.
+        let mut segments = HashMap::<_, Vec<_>>::new();
+        for (segment, (_, systems)) in stages.iter().enumerate() {
+            for (_, constraint) in systems.iter() {
+                for label in constraint.labels.iter() {
+                    segments.entry(label.clone()).or_default().push(segment);
+                }
+            }
+        }
+        for (segment, (_, systems)) in stages.iter().enumerate() {
+            for (system, constraint) in systems.iter() {
+                let before = constraint.before.iter().map(|label| (label, true));
+                let after = constraint.after.iter().map(|label| (label, false));
+                for (label, before) in before.chain(after) {
+                    let mut others = segments.get(label).into_iter().flatten();
+                    if others.any(|&other| other != segment && (other < segment) == before) {
+                        errors.push(Error::StageOrder {
+                            system: system.name().into(),
+                            label: label.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut ordered = Vec::with_capacity(stages.len());
+        for (stage, systems) in stages {
+            if systems.is_empty() {
+                continue;
+            }
+            match order(systems, &labels) {
+                Ok(systems) => ordered.push((stage, systems)),
                 Err(error) => errors.push(error),
             }
         }
 
         match Error::All(errors).flatten(true) {
             Some(error) => Err(error),
-            None => Runner::new(parallelism, schedules, self.world),
+            None => Runner::staged(parallelism, ordered, self.world),
+        }
+    }
+
+    fn group<F: FnOnce(Self) -> Self>(mut self, schedule: F) -> Self {
+        let start = self.systems.len();
+        self = schedule(self);
+        self.last = start..self.systems.len();
+        self
+    }
+
+    fn constrain(&mut self, mut constrain: impl FnMut(&mut Constraint)) {
+        for (_, constraint) in self.systems[self.last.clone()].iter_mut() {
+            constrain(constraint);
         }
     }
 
@@ -80,3 +220,88 @@ impl Scheduler<'_> {
         self
     }
 }
+
+/// Sorts the systems topologically based on their constraints. Systems that are not constrained relative to each other
+/// keep their declaration order.
+fn order(systems: Vec<(System, Constraint)>, labels: &HashSet<String>) -> Result<Vec<System>> {
+    let mut indices = HashMap::<_, Vec<_>>::new();
+    for (index, (_, constraint)) in systems.iter().enumerate() {
+        for label in constraint.labels.iter() {
+            indices.entry(label.as_str()).or_default().push(index);
+        }
+    }
+
+    let mut successors = vec![Vec::new(); systems.len()];
+    let mut predecessors = vec![0; systems.len()];
+    for (index, (_, constraint)) in systems.iter().enumerate() {
+        let before = constraint.before.iter().map(|label| (label, true));
+        let after = constraint.after.iter().map(|label| (label, false));
+        for (label, before) in before.chain(after) {
+            if !labels.contains(label) {
+                return Err(Error::MissingLabel {
+                    label: label.clone(),
+                });
+            }
+
+            // Constraints on labels that belong to other stages are checked by `Scheduler::schedule_with`.
+            for &other in indices.get(label.as_str()).into_iter().flatten() {
+                if other != index {
+                    let (source, target) = if before {
+                        (index, other)
+                    } else {
+                        (other, index)
+                    };
+                    successors[source].push(target);
+                    predecessors[target] += 1;
+                }
+            }
+        }
+    }
+
+    let mut queue: BinaryHeap<_> = predecessors
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count == 0)
+        .map(|(index, _)| Reverse(index))
+        .collect();
+    let mut sorted = Vec::with_capacity(systems.len());
+    while let Some(Reverse(index)) = queue.pop() {
+        sorted.push(index);
+        for &successor in successors[index].iter() {
+            predecessors[successor] -= 1;
+            if predecessors[successor] == 0 {
+                queue.push(Reverse(successor));
+            }
+        }
+    }
+
+    if sorted.len() < systems.len() {
+        // Every remaining system has a remaining predecessor so walking them backwards must end up on a cycle.
+        let mut path = Vec::new();
+        let mut current = (0..systems.len()).find(|&index| predecessors[index] > 0);
+        while let Some(index) = current {
+            if let Some(position) = path.iter().position(|&other| other == index) {
+                return Err(Error::OrderCycle {
+                    systems: path[position..]
+                        .iter()
+                        .rev()
+                        .map(|&index: &usize| systems[index].0.name().into())
+                        .collect(),
+                });
+            }
+            path.push(index);
+            current = (0..systems.len())
+                .find(|&other| predecessors[other] > 0 && successors[other].contains(&index));
+        }
+        return Err(Error::FailedToSchedule);
+    }
+
+    let mut systems: Vec<_> = systems
+        .into_iter()
+        .map(|(system, _)| Some(system))
+        .collect();
+    Ok(sorted
+        .into_iter()
+        .filter_map(|index| systems[index].take())
+        .collect())
+}
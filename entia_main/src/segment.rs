@@ -3,8 +3,8 @@ use crate::{
     error::{Error, Result},
     identify,
     meta::{Meta, Metas},
-    resource::Resource,
     store::Store,
+    Resource,
 };
 use entia_core::{utility::next_power_of_2, Flags, FullIterator, IntoFlags};
 use std::{
@@ -25,14 +25,12 @@ pub enum Flag {
     Default = 1 << 1,
 }
 
-#[derive(Default)]
+#[derive(Resource, Default)]
 pub struct Segments {
     // SAFETY: This vector may only 'push', never 'pop'; otherwise some unsafe index access may become invalid.
     segments: Vec<Segment>,
 }
 
-impl Resource for Segments {}
-
 // The 'entity_store' must be kept separate from the 'component_stores' to prevent undesired behavior that may arise
 // from using queries such as '&mut Entity' or templates such as 'Add<Entity>'.
 pub struct Segment {
@@ -57,6 +55,7 @@ impl Segments {
         component_metas: I,
         metas: &Metas,
     ) -> &mut Segment {
+        let descriptions = metas.descriptions();
         let mut metas: Vec<_> = [metas.entity()]
             .into_iter()
             .chain(component_metas)
@@ -70,6 +69,14 @@ impl Segments {
             None => {
                 let index = self.segments.len();
                 let segment = Segment::new(index, 0, types, metas);
+                descriptions.describe(segment.identifier, format!("segment #{}", index));
+                for store in segment.stores.iter() {
+                    let name = store.meta().name();
+                    descriptions.describe(
+                        store.identifier(),
+                        format!("store of `{}` in segment #{}", name, index),
+                    );
+                }
                 self.segments.push(segment);
                 index
             }
@@ -154,6 +161,11 @@ impl Segment {
         self.count
     }
 
+    #[inline]
+    pub const fn flags(&self) -> &Flags<Flag> {
+        &self.flags
+    }
+
     #[inline]
     pub const fn types(&self) -> &HashSet<TypeId> {
         &self.types
@@ -186,7 +198,7 @@ impl Segment {
             self.count -= 1;
             if index == self.count {
                 for store in self.stores() {
-                    unsafe { Store::drop(&store, index, 1) };
+                    unsafe { Store::drop(store, index, 1) };
                 }
                 false
             } else {
@@ -200,9 +212,26 @@ impl Segment {
         }
     }
 
+    /// SAFETY: The data at 'index' must have been moved elsewhere since it will not be dropped.
+    pub(crate) unsafe fn forget_at(&mut self, index: usize) -> bool {
+        if index < self.count {
+            self.count -= 1;
+            if index == self.count {
+                false
+            } else {
+                for store in self.stores() {
+                    Store::copy((store, self.count), (store, index), 1);
+                }
+                true
+            }
+        } else {
+            false
+        }
+    }
+
     pub fn clear(&mut self) {
         for store in self.stores() {
-            unsafe { Store::drop(&store, 0, self.count) };
+            unsafe { Store::drop(store, 0, self.count) };
         }
         self.count = 0;
     }
@@ -0,0 +1,97 @@
+use crate::identify;
+use std::time::Duration;
+
+/// Determines how many times the systems of a `Stage` run in a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tick {
+    /// Runs once every frame.
+    Frame,
+    /// Runs once every `step` of elapsed time. Elapsed time accumulates across frames and at most `steps` are caught up
+    /// in a single frame; the excess time is dropped.
+    Fixed { step: Duration, steps: usize },
+    /// Runs once every `n` frames, starting with the first one.
+    Frames(usize),
+}
+
+/// A named group of systems that share a `Tick` policy. The boundaries between stages are synchronization points such
+/// that a stage only begins when all the runs of the previous ones are done. Clones of a stage are the same stage: the
+/// systems that are given to a `Runner` with any of them share a single tick state.
+#[derive(Debug, Clone)]
+pub struct Stage {
+    identifier: usize,
+    name: String,
+    tick: Tick,
+    accumulator: Duration,
+    frame: usize,
+}
+
+impl Tick {
+    #[inline]
+    pub fn fixed(step: Duration, steps: usize) -> Self {
+        Self::Fixed { step, steps }
+    }
+
+    /// A fixed timestep of `rate` steps per second.
+    #[inline]
+    pub fn rate(rate: f64, steps: usize) -> Self {
+        Self::fixed(Duration::from_secs_f64(1.0 / rate), steps)
+    }
+}
+
+impl Stage {
+    pub fn new(name: impl Into<String>, tick: Tick) -> Self {
+        Self {
+            identifier: identify(),
+            name: name.into(),
+            tick,
+            accumulator: Duration::ZERO,
+            frame: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn identifier(&self) -> usize {
+        self.identifier
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub const fn tick(&self) -> Tick {
+        self.tick
+    }
+
+    /// Returns the number of times the stage must run for a frame that lasted `delta`.
+    pub(crate) fn ticks(&mut self, delta: Duration) -> usize {
+        match self.tick {
+            Tick::Frame => 1,
+            Tick::Fixed { step, steps } if step.is_zero() => steps.min(1),
+            Tick::Fixed { step, steps } => {
+                self.accumulator += delta;
+                let count = (self.accumulator.as_nanos() / step.as_nanos()) as usize;
+                if count > steps {
+                    // Catching up on every missed step could cause the frames to take longer and longer.
+                    self.accumulator = Duration::from_nanos(
+                        (self.accumulator.as_nanos() % step.as_nanos()) as u64,
+                    );
+                    steps
+                } else {
+                    self.accumulator -= step * count as u32;
+                    count
+                }
+            }
+            Tick::Frames(frames) => {
+                let frame = self.frame;
+                self.frame = (frame + 1) % frames.max(1);
+                if frame == 0 {
+                    1
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
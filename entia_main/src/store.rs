@@ -1,9 +1,11 @@
 use crate::{
     error::{Error, Result},
     identify,
-    meta::Meta,
+    meta::{Deserializer, Meta},
+    transfer::Remap,
 };
-use std::{cell::Cell, ptr::NonNull, slice::from_raw_parts_mut, sync::Arc};
+use entia_serialize::node::{self, Node};
+use std::{cell::Cell, ptr::NonNull, result, slice::from_raw_parts_mut, sync::Arc};
 
 pub struct Store {
     identifier: usize,
@@ -108,14 +110,48 @@ impl Store {
         }
     }
 
-    /// SAFETY: The 'index' must be within the bounds of the store.
+    /// SAFETY: The 'index' must be within the bounds of the store and its value must be dropped or uninitialized. The
+    /// value is initialized even when the deserialization fails.
+    pub unsafe fn deserialize(&self, index: usize, node: &Node) -> Result {
+        let deserializer = self.deserializer()?;
+        self.deserialized((deserializer.new)(node, (self.data.get(), index)))
+    }
+
+    /// Deserializes the `node` onto the existing value such that the fields that it does not hold are preserved.
+    ///
+    /// SAFETY: The 'index' must be within the bounds of the store and its value must be initialized.
+    pub unsafe fn apply(&self, index: usize, node: &Node) -> Result {
+        let deserializer = self.deserializer()?;
+        self.deserialized((deserializer.apply)(node, (self.data.get(), index)))
+    }
+
+    fn deserializer(&self) -> Result<&Deserializer> {
+        let meta = self.meta();
+        meta.deserializer
+            .as_ref()
+            .ok_or(Error::MissingDeserialize { name: meta.name() })
+    }
+
+    fn deserialized(&self, result: result::Result<(), node::Error>) -> Result {
+        let meta = self.meta();
+        match result {
+            Ok(()) => Ok(()),
+            Err(node::Error::Excess) => Err(Error::UnknownField { name: meta.name() }),
+            Err(node::Error::Invalid) => Err(Error::FailedToDeserialize { name: meta.name() }),
+        }
+    }
+
+    /// SAFETY: The 'index' must be within the bounds of the store and no other reference to the item may be alive.
     #[inline]
+    #[allow(clippy::mut_from_ref)]
     pub unsafe fn get<T: Send + Sync + 'static>(&self, index: usize) -> &mut T {
         &mut *self.data::<T>().add(index)
     }
 
-    /// SAFETY: Both 'index' and 'count' must be within the bounds of the store.
+    /// SAFETY: Both 'index' and 'count' must be within the bounds of the store and no other reference to the items may
+    /// be alive.
     #[inline]
+    #[allow(clippy::mut_from_ref)]
     pub unsafe fn get_all<T: Send + Sync + 'static>(&self, count: usize) -> &mut [T] {
         from_raw_parts_mut(self.data::<T>(), count)
     }
@@ -126,10 +162,7 @@ impl Store {
     }
 
     #[inline]
-    pub unsafe fn set_all<T: Send + Sync + 'static>(&self, index: usize, items: &[T])
-    where
-        T: Copy,
-    {
+    pub unsafe fn set_all<T: Copy + Send + Sync + 'static>(&self, index: usize, items: &[T]) {
         let source = items.as_ptr().cast::<T>();
         let target = self.data::<T>().add(index);
         source.copy_to_nonoverlapping(target, items.len());
@@ -153,6 +186,14 @@ impl Store {
         (meta.copy)((pointer, source_index), (pointer, target_index), count);
     }
 
+    /// SAFETY: Both 'index' and 'count' must be within the bounds of the store.
+    #[inline]
+    pub unsafe fn remap(&self, index: usize, count: usize, remap: &Remap) {
+        if let Some(remapper) = self.meta().remapper.as_ref() {
+            (remapper.remap)((self.data.get(), index), count, remap);
+        }
+    }
+
     #[inline]
     pub unsafe fn drop(&self, index: usize, count: usize) {
         (self.meta().drop)(self.data.get(), index, count);
@@ -1,10 +1,11 @@
 use crate::{
-    depend::Dependency,
+    depend::{merge, Dependency},
     error::{Error, Result},
     identify,
     inject::{Adapt, Cast, Context, Get, Inject},
     output::IntoOutput,
     run::{as_mut, Run},
+    tuples,
     world::World,
 };
 use entia_core::{utility::short_type_name, Call};
@@ -19,9 +20,29 @@ pub struct System {
     identifier: usize,
     pub(crate) name: String,
     pub(crate) state: Arc<dyn Any + Send + Sync>,
+    pub(crate) policy: Policy,
+    pub(crate) labels: Vec<String>,
     schedule: Box<dyn FnMut(&mut dyn Any, &mut World) -> Vec<Run>>,
 }
 
+/// Determines what a `Runner` does when a run of a system fails. Every error is given to the handler of the runner
+/// (see `Runner::handle`) but only `Abort` stops the frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Stops the frame and returns the error from `Runner::run`.
+    #[default]
+    Abort,
+    /// Skips the runs that are strongly blocked by the failed run, directly or not, for the rest of the frame.
+    Skip,
+    /// Considers the failed run as done such that the runs that depend on it execute as usual. A failed run that
+    /// prepares the state of its system (see `Schedule::pre`) skips like `Skip` instead since the run of the system
+    /// would access invalid state.
+    Continue,
+    /// Skips like `Skip` and tries again on the next frame, for at most the given number of consecutive frames. The
+    /// failure that follows them stops the frame like `Abort`.
+    Retry(usize),
+}
+
 pub trait IntoSystem<M = ()> {
     type Input;
     fn system(self, input: Self::Input, world: &mut World) -> Result<System>;
@@ -38,6 +59,16 @@ impl System {
         &self.name
     }
 
+    #[inline]
+    pub const fn policy(&self) -> Policy {
+        self.policy
+    }
+
+    #[inline]
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
     pub fn schedule(&mut self, world: &mut World) -> Vec<Run> {
         let state = as_mut(&mut self.state);
         (self.schedule)(state, world)
@@ -85,14 +116,33 @@ impl<M, S: IntoSystem<M>, E: Into<Error>> IntoSystem<(M, S)> for result::Result<
     }
 }
 
-impl<'a, I: Inject, O: IntoOutput, C: Call<I, O> + Send + Sync + 'static> IntoSystem<(I, O, C)>
-    for C
+/// Builds the system of a function from the types of its parameters. It is implemented for parameters that implement
+/// `Inject` and for a single `&mut World` parameter, which gives the function exclusive access to the world. Going
+/// through this trait rather than implementing `IntoSystem` for both kinds of functions keeps the marker of `IntoSystem`
+/// inferable since a closure matches every blanket implementation.
+pub trait Parameters<O, C>: Sized {
+    type Input;
+    fn system(run: C, input: Self::Input, world: &mut World) -> Result<System>;
+}
+
+impl<I: Parameters<O, C>, O: IntoOutput, C: Call<I, O> + Send + Sync + 'static>
+    IntoSystem<(I, O, C)> for C
+{
+    type Input = I::Input;
+
+    #[inline]
+    fn system(self, input: I::Input, world: &mut World) -> Result<System> {
+        I::system(self, input, world)
+    }
+}
+
+impl<'a, I: Inject, O: IntoOutput, C: Call<I, O> + Send + Sync + 'static> Parameters<O, C> for I
 where
     I::State: Get<'a, Item = I>,
 {
     type Input = I::Input;
 
-    fn system(self, input: I::Input, world: &mut World) -> Result<System> {
+    fn system(run: C, input: I::Input, world: &mut World) -> Result<System> {
         let mut schedules = Vec::new();
         let cast = Cast::<(I::State, C)>::new();
         let map = cast.clone().map(|(state, _)| state);
@@ -104,7 +154,9 @@ where
         Ok(System {
             identifier,
             name: short_type_name::<I>(),
-            state: Arc::new((state, self)),
+            state: Arc::new((state, run)),
+            policy: Policy::Abort,
+            labels: Vec::new(),
             schedule: Box::new(move |state, world| {
                 let mut pre = Vec::new();
                 let mut post = Vec::new();
@@ -138,6 +190,155 @@ where
     }
 }
 
+/// Runs the system `S` only when the condition `C` returns `true`. The condition executes at the beginning of the run of
+/// the system and their dependencies are merged such that both may access the same data, even mutably. The
+/// dependencies of the condition keep blocking other systems even when the system is skipped.
+pub struct If<C, S>(pub C, pub S);
+
+impl<
+        'a,
+        IC: Inject,
+        IS: Inject,
+        O: IntoOutput,
+        C: Call<IC, bool> + Send + Sync + 'static,
+        S: Call<IS, O> + Send + Sync + 'static,
+    > IntoSystem<(IC, IS, O, C, S)> for If<C, S>
+where
+    IC::State: Get<'a, Item = IC>,
+    IS::State: Get<'a, Item = IS>,
+{
+    type Input = (IC::Input, IS::Input);
+
+    fn system(self, input: Self::Input, world: &mut World) -> Result<System> {
+        let mut schedules = Vec::new();
+        let cast = Cast::<((IC::State, IS::State), (C, S))>::new();
+        let map = cast.clone().map(|(state, _)| state);
+        let context = Context::new(map.clone(), &mut schedules, world);
+        let identifier = context.identifier();
+        let state = <(IC, IS)>::initialize(input, context)?;
+        world.modify();
+        let If(condition, system) = self;
+
+        Ok(System {
+            identifier,
+            name: short_type_name::<IS>(),
+            state: Arc::new((state, (condition, system))),
+            policy: Policy::Abort,
+            labels: Vec::new(),
+            schedule: Box::new(move |state, world| {
+                let mut pre = Vec::new();
+                let mut post = Vec::new();
+
+                for schedule in schedules.iter_mut() {
+                    let runs = schedule(state, world);
+                    pre.extend(runs.0);
+                    post.extend(runs.1);
+                }
+
+                match map.adapt(state) {
+                    Some((condition, system)) => {
+                        let cast = cast.clone();
+                        // The items of the condition are dropped before the ones of the system are created, so the
+                        // accesses of the condition may be subsumed by the ones of the system.
+                        let mut dependencies = Vec::new();
+                        merge(
+                            &mut dependencies,
+                            [IC::depend(condition), IS::depend(system)],
+                        );
+                        pre.push(Run::new(
+                            move |state| match cast.adapt(state) {
+                                Some(((condition, system), (check, run))) => {
+                                    let condition = unsafe { &mut *(condition as *mut IC::State) };
+                                    if check.call(unsafe { condition.get() }) {
+                                        let system = unsafe { &mut *(system as *mut IS::State) };
+                                        run.call(unsafe { system.get() }).output()
+                                    } else {
+                                        Ok(())
+                                    }
+                                }
+                                None => Ok(()),
+                            },
+                            dependencies,
+                        ));
+                        pre.extend(post);
+                        pre
+                    }
+                    None => vec![],
+                }
+            }),
+        })
+    }
+}
+
+/// Runs the system `A` and then the system `B` with the output of `A` as its first parameter. Both systems execute as a
+/// single run such that the dependencies of the combined system are the union of theirs.
+pub struct Pipe<A, B>(pub A, pub B);
+
+pub trait IntoPipe<M>: Sized {
+    #[inline]
+    fn pipe<S>(self, system: S) -> Pipe<Self, S> {
+        Pipe(self, system)
+    }
+}
+
+impl<I, O, F: Call<I, O>> IntoPipe<(I, O)> for F {}
+
+macro_rules! pipe {
+    ($($p:ident, $t:ident),*) => {
+        impl<
+                'a,
+                IA: Inject,
+                $($t: Inject,)*
+                V,
+                O: IntoOutput,
+                A: Call<IA, V> + Send + Sync + 'static,
+                B: FnMut(V, $($t,)*) -> O + Send + Sync + 'static,
+            > IntoSystem<(IA, ($($t,)*), V, O, A, B)> for Pipe<A, B>
+        where
+            IA::State: Get<'a, Item = IA>,
+            $($t::State: Get<'a, Item = $t>,)*
+        {
+            type Input = (IA::Input, <($($t,)*) as Inject>::Input);
+
+            fn system(self, input: Self::Input, world: &mut World) -> Result<System> {
+                let Pipe(mut first, mut second) = self;
+                let run = move |(input, ($($p,)*)): (IA, ($($t,)*))| {
+                    second(first.call(input), $($p,)*).output()
+                };
+                run.system((input,), world)
+            }
+        }
+    };
+}
+
+tuples!(pipe);
+
+/// A function that takes `&mut World` has exclusive access to the world. The `Runner` treats it as a full barrier and
+/// reschedules the remaining systems if it modifies the world.
+impl<O: IntoOutput, F: FnMut(&mut World) -> O + Send + Sync + 'static> Parameters<O, F>
+    for (&mut World,)
+{
+    type Input = ();
+
+    fn system(run: F, _: Self::Input, _: &mut World) -> Result<System> {
+        Ok(System {
+            identifier: identify(),
+            name: short_type_name::<F>(),
+            state: Arc::new(run),
+            policy: Policy::Abort,
+            labels: Vec::new(),
+            schedule: Box::new(|_, _| {
+                vec![Run::exclusive(|state, world| {
+                    match state.downcast_mut::<F>() {
+                        Some(run) => run(world).output(),
+                        None => Ok(()),
+                    }
+                })]
+            }),
+        })
+    }
+}
+
 pub struct Barrier;
 
 impl IntoSystem for Barrier {
@@ -148,6 +349,8 @@ impl IntoSystem for Barrier {
             identifier: identify(),
             name: "barrier".into(),
             state: Arc::new(()),
+            policy: Policy::Abort,
+            labels: Vec::new(),
             schedule: Box::new(|_, _| vec![Run::new(|_| Ok(()), [Dependency::Unknown])]),
         })
     }
@@ -13,6 +13,7 @@ use entia_core::Marker;
 use std::{collections::HashMap, marker::PhantomData, sync::Arc};
 
 pub struct DeclareContext<'a> {
+    world: usize,
     metas_index: usize,
     segment_metas: &'a mut Vec<Vec<Arc<Meta>>>,
     metas: &'a mut Metas,
@@ -32,6 +33,7 @@ pub struct CountContext<'a> {
     entity_parent: Option<usize>,
     entity_previous: &'a mut Option<usize>,
     entity_indices: &'a mut Vec<EntityIndices>,
+    error: &'a mut Option<Error>,
 }
 
 pub struct ApplyContext<'a> {
@@ -42,6 +44,7 @@ pub struct ApplyContext<'a> {
     store_index: usize,
     segment_indices: &'a [SegmentIndices],
     initialize: (usize, &'a mut Vec<(u32, Datum)>),
+    error: &'a mut Option<Error>,
 }
 
 pub trait Template {
@@ -96,23 +99,36 @@ impl<T: SpawnTemplate> Marker<T> for SpawnMarker {}
 
 impl<'a> DeclareContext<'a> {
     pub(crate) fn new(
+        world: usize,
         metas_index: usize,
         segment_metas: &'a mut Vec<Vec<Arc<Meta>>>,
         metas: &'a mut Metas,
     ) -> Self {
         Self {
+            world,
             metas_index,
             segment_metas,
             metas,
         }
     }
 
-    pub fn owned(&mut self) -> DeclareContext {
+    /// The identifier of the world in which the template is declared.
+    #[inline]
+    pub const fn world(&self) -> usize {
+        self.world
+    }
+
+    #[inline]
+    pub fn metas(&self) -> &Metas {
+        self.metas
+    }
+
+    pub fn owned(&mut self) -> DeclareContext<'_> {
         self.with(self.metas_index)
     }
 
-    pub fn with(&mut self, metas_index: usize) -> DeclareContext {
-        DeclareContext::new(metas_index, self.segment_metas, self.metas)
+    pub fn with(&mut self, metas_index: usize) -> DeclareContext<'_> {
+        DeclareContext::new(self.world, metas_index, self.segment_metas, self.metas)
     }
 
     pub fn meta<C: Component>(&mut self) -> Arc<Meta> {
@@ -121,6 +137,13 @@ impl<'a> DeclareContext<'a> {
         meta
     }
 
+    /// Declares a component of which the type is only known through its `meta`.
+    pub fn meta_with(&mut self, meta: Arc<Meta>) -> Arc<Meta> {
+        let meta = self.metas.get_or_insert(meta);
+        self.segment_metas[self.metas_index].push(meta.clone());
+        meta
+    }
+
     pub fn child<T>(&mut self, scope: impl FnOnce(usize, DeclareContext) -> T) -> T {
         let metas_index = self.segment_metas.len();
         self.segment_metas.push(Vec::new());
@@ -147,11 +170,11 @@ impl<'a> InitializeContext<'a> {
         &self.segments[self.segment_indices[self.segment_index].segment]
     }
 
-    pub fn owned(&mut self) -> InitializeContext {
+    pub fn owned(&mut self) -> InitializeContext<'_> {
         self.with(self.segment_index)
     }
 
-    pub fn with(&mut self, segment_index: usize) -> InitializeContext {
+    pub fn with(&mut self, segment_index: usize) -> InitializeContext<'_> {
         InitializeContext::new(
             segment_index,
             self.segment_indices,
@@ -175,6 +198,7 @@ impl<'a> CountContext<'a> {
         segment_indices: &'a mut [SegmentIndices],
         entity_previous: &'a mut Option<usize>,
         entity_indices: &'a mut Vec<EntityIndices>,
+        error: &'a mut Option<Error>,
     ) -> Self {
         Self {
             segment_index: 0,
@@ -183,10 +207,11 @@ impl<'a> CountContext<'a> {
             entity_parent: None,
             entity_previous,
             entity_indices,
+            error,
         }
     }
 
-    pub fn owned(&mut self) -> CountContext {
+    pub fn owned(&mut self) -> CountContext<'_> {
         CountContext {
             segment_index: self.segment_index,
             segment_indices: self.segment_indices,
@@ -194,6 +219,7 @@ impl<'a> CountContext<'a> {
             entity_parent: self.entity_parent,
             entity_previous: self.entity_previous,
             entity_indices: self.entity_indices,
+            error: self.error,
         }
     }
 
@@ -203,7 +229,7 @@ impl<'a> CountContext<'a> {
         entity_index: usize,
         entity_parent: Option<usize>,
         entity_previous: &'b mut Option<usize>,
-    ) -> CountContext {
+    ) -> CountContext<'b> {
         let mut context = self.owned();
         context.segment_index = segment_index;
         context.entity_index = entity_index;
@@ -228,12 +254,35 @@ impl<'a> CountContext<'a> {
         }
 
         segment_indices.count += 1;
-        scope(self.with(
-            segment_index,
-            entity_index,
-            Some(self.entity_index),
-            &mut None,
-        ))
+        scope(self.with(segment_index, entity_index, Some(entity_index), &mut None))
+    }
+
+    /// Reports that the template can not be created, in which case the entities of its root are not created and the
+    /// `error` is returned when the `Create` resolves.
+    pub fn fail(&mut self, error: Error) {
+        fail(self.error, error);
+    }
+
+    /// Moves the current entity to the segment at `segment_index` such that the segment that was declared for it can
+    /// be chosen when counting.
+    pub fn relocate(&mut self, segment_index: usize) {
+        let entity = &mut self.entity_indices[self.entity_index];
+        let (previous, offset) = (entity.segment, entity.offset);
+        if previous == segment_index {
+            return;
+        }
+
+        entity.segment = segment_index;
+        entity.offset = self.segment_indices[segment_index].count;
+        self.segment_indices[segment_index].count += 1;
+        self.segment_indices[previous].count -= 1;
+        self.segment_index = segment_index;
+        // The entities that were counted after this one in its previous segment take its place.
+        for entity in self.entity_indices[self.entity_index + 1..].iter_mut() {
+            if entity.segment == previous && entity.offset > offset {
+                entity.offset -= 1;
+            }
+        }
     }
 }
 
@@ -244,6 +293,7 @@ impl<'a> ApplyContext<'a> {
         entity_indices: &'a [EntityIndices],
         segment_indices: &'a [SegmentIndices],
         initialize: &'a mut Vec<(u32, Datum)>,
+        error: &'a mut Option<Error>,
     ) -> Self {
         Self {
             entity_root,
@@ -253,6 +303,7 @@ impl<'a> ApplyContext<'a> {
             store_index: 0,
             segment_indices,
             initialize: (initialize.len(), initialize),
+            error,
         }
     }
 
@@ -262,7 +313,7 @@ impl<'a> ApplyContext<'a> {
     }
 
     #[inline]
-    pub const fn family(&self) -> Family {
+    pub const fn family(&self) -> Family<'_> {
         Family::new(
             self.entity_root.0,
             self.entity_root.1 + self.entity_index,
@@ -278,7 +329,7 @@ impl<'a> ApplyContext<'a> {
     }
 
     #[inline]
-    pub fn owned(&mut self) -> ApplyContext {
+    pub fn owned(&mut self) -> ApplyContext<'_> {
         ApplyContext {
             entity_root: self.entity_root,
             entity_index: self.entity_index,
@@ -287,11 +338,18 @@ impl<'a> ApplyContext<'a> {
             store_index: self.store_index,
             segment_indices: self.segment_indices,
             initialize: (self.initialize.0, self.initialize.1),
+            error: self.error,
         }
     }
 
+    /// Reports an error of the template. Since its entities were already reserved, the template must still initialize
+    /// all of their components. The `error` is returned when the `Create` resolves.
+    pub fn fail(&mut self, error: Error) {
+        fail(self.error, error);
+    }
+
     #[inline]
-    pub fn with(&mut self, entity_index: usize, store_index: usize) -> ApplyContext {
+    pub fn with(&mut self, entity_index: usize, store_index: usize) -> ApplyContext<'_> {
         let mut context = self.owned();
         context.entity_index = entity_index;
         context.store_index = store_index;
@@ -349,6 +407,14 @@ impl<'a> ApplyContext<'a> {
     }
 }
 
+/// Keeps the `error` along with the ones that were already reported.
+pub(crate) fn fail(errors: &mut Option<Error>, error: Error) {
+    *errors = Some(match errors.take() {
+        Some(errors) => errors.merge(error),
+        None => error,
+    });
+}
+
 unsafe impl<T: SpawnTemplate> SpawnTemplate for Option<T> {}
 unsafe impl<T: SpawnTemplate + LeafTemplate> LeafTemplate for Option<T> {}
 
@@ -643,6 +709,7 @@ macro_rules! template {
         unsafe impl<$($t: StaticTemplate,)*> StaticTemplate for ($($t,)*) {}
         unsafe impl<$($t: LeafTemplate,)*> LeafTemplate for ($($t,)*) {}
 
+        #[allow(clippy::unused_unit)]
         impl<$($t: Template,)*> Template for ($($t,)*) {
             type Input = ($($t::Input,)*);
             type State = ($($t::State,)*);
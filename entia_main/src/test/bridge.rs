@@ -0,0 +1,65 @@
+use super::*;
+use std::thread;
+
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct OnPacket(usize);
+
+#[test]
+fn sender_pushes_from_other_threads() -> Result {
+    let mut world = World::new();
+    let sender = world.sender::<OnPacket>();
+    let mut receive = world.injector::<Receive<OnPacket>>()?;
+    let threads: Vec<_> = (0..4)
+        .map(|index| {
+            let sender = sender.clone();
+            thread::spawn(move || sender.send(OnPacket(index)))
+        })
+        .collect();
+    for thread in threads {
+        assert!(thread.join().unwrap());
+    }
+
+    let mut received = receive.run(&mut world, |receive| {
+        receive.map(|message| message.0).collect::<Vec<_>>()
+    })?;
+    received.sort();
+    assert_eq!(received, [0, 1, 2, 3]);
+    Ok(())
+}
+
+#[test]
+fn sender_is_held_during_replay() -> Result {
+    let mut world = World::new();
+    let sender = world.sender::<OnPacket>();
+    let mut receive = world.injector::<Receive<OnPacket>>()?;
+    world.replay::<OnPacket>([]);
+    assert!(sender.send(OnPacket(0)));
+    assert_eq!(receive.run(&mut world, |receive| receive.len())?, 0);
+
+    world.resume::<OnPacket>();
+    let received = receive.run(&mut world, |receive| {
+        receive.map(|message| message.0).collect::<Vec<_>>()
+    })?;
+    assert_eq!(received, [0]);
+    Ok(())
+}
+
+#[test]
+fn outbox_receives_emitted_messages() -> Result {
+    let mut world = World::new();
+    let outbox = world.outbox::<OnPacket>();
+    let mut emit = world.injector::<Emit<OnPacket>>()?;
+    emit.run(&mut world, |mut emit| emit.all([OnPacket(1), OnPacket(2)]))?;
+
+    let consumer = thread::spawn(move || outbox.drain().collect::<Vec<_>>());
+    assert_eq!(consumer.join().unwrap(), [OnPacket(1), OnPacket(2)]);
+    Ok(())
+}
+
+#[test]
+fn sender_fails_when_world_is_dropped() {
+    let mut world = World::new();
+    let sender = world.sender::<OnPacket>();
+    drop(world);
+    assert!(!sender.send(OnPacket(0)));
+}
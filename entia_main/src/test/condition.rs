@@ -0,0 +1,71 @@
+use super::*;
+use entia::depend::{Dependency, Order};
+
+#[derive(Resource, Default)]
+pub struct Playing(bool);
+#[derive(Resource, Default)]
+pub struct Count(usize);
+
+#[test]
+fn skips_system_when_condition_is_false() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add_if(
+            |playing: &Playing| playing.0,
+            |count: &mut Count| count.0 += 1,
+        )
+        .schedule()?;
+
+    runner.run(&mut world)?;
+    world
+        .injector::<&mut Playing>()?
+        .run(&mut world, |playing| playing.0 = true)?;
+    runner.run(&mut world)?;
+    runner.run(&mut world)?;
+    world
+        .injector::<&Count>()?
+        .run(&mut world, |count| assert_eq!(count.0, 2))?;
+    Ok(())
+}
+
+#[test]
+fn condition_may_read_what_system_writes() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add_if(
+            |count: &Count| count.0 < 3,
+            |count: &mut Count| count.0 += 1,
+        )
+        .schedule()?;
+
+    for _ in 0..5 {
+        runner.run(&mut world)?;
+    }
+    world
+        .injector::<&Count>()?
+        .run(&mut world, |count| assert_eq!(count.0, 3))?;
+    Ok(())
+}
+
+#[test]
+fn condition_shares_the_run_of_its_system() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add_if(
+            |_: &Playing, count: &Count| count.0 < 3,
+            |count: &mut Count| count.0 += 1,
+        )
+        .schedule()?;
+    runner.run(&mut world)?;
+
+    let graph = runner.graph();
+    assert_eq!(graph.nodes.len(), 1);
+    let dependencies = &graph.nodes[0].dependencies;
+    assert!(dependencies.contains(&Dependency::read::<Playing>(Order::Strict)));
+    assert!(dependencies.contains(&Dependency::write::<Count>(Order::Strict)));
+    assert!(!dependencies.contains(&Dependency::read::<Count>(Order::Strict)));
+    Ok(())
+}
@@ -1,3 +1,7 @@
+use entia::{
+    depend::{Dependency, Order},
+    entities::Entities,
+};
 use entia_check::{FullGenerate, Generate, IntoGenerate};
 use std::collections::HashSet;
 
@@ -5,7 +9,9 @@ use super::*;
 
 #[test]
 fn has_entity_count() -> Result {
-    let position = <(f64, f64, f64)>::generator().map(|(x, y, z)| Position(x, y, z));
+    // NaN is not equal to itself, so it would fail the comparisons of positions.
+    let number = f64::generator().map(|value| if value.is_nan() { 0. } else { value });
+    let position = (number.clone(), number.clone(), number).map(|(x, y, z)| Position(x, y, z));
     let count = (0usize..1000).generator();
     for (position, count) in (position, count).sample(100) {
         let mut world = World::new();
@@ -43,3 +49,121 @@ fn has_entity_count() -> Result {
     }
     Ok(())
 }
+
+#[test]
+fn deferred_creation_applies_each_segment() -> Result {
+    let mut world = World::new();
+    let mut create = world.injector::<Create<(Add<Position>, Option<Spawn<Add<Velocity>>>)>>()?;
+    let mut positions = world.injector::<Query<&Position>>()?;
+    let mut families = world.injector::<(Families, Query<&Velocity>)>()?;
+
+    let roots: Vec<_> = create.run(&mut world, |mut create| {
+        create
+            .all(Iterator::map(0..100, |index| {
+                let child = Spawn::new(Add::new(Velocity(index as f64, 0., 0.)));
+                (
+                    Add::new(Position(index as f64, 0., 0.)),
+                    (index % 2 == 0).then_some(child),
+                )
+            }))
+            .roots()
+            .map(|family| family.entity())
+            .collect()
+    })?;
+
+    positions.run(&mut world, |positions| {
+        for (index, &root) in roots.iter().enumerate() {
+            assert_eq!(positions.get(root), Some(&Position(index as f64, 0., 0.)));
+        }
+    })?;
+    families.run(&mut world, |(families, velocities)| {
+        assert_eq!(velocities.into_iter().count(), 50);
+        for (index, &root) in roots.iter().enumerate() {
+            let children: Vec<_> = families
+                .family(root)
+                .children()
+                .map(|child| velocities.get(child.entity()).cloned())
+                .collect();
+            match index % 2 {
+                0 => assert_eq!(children, [Some(Velocity(index as f64, 0., 0.))]),
+                _ => assert!(children.is_empty()),
+            }
+        }
+    })?;
+
+    let mut runner = world
+        .scheduler()
+        .add(|_: Create<(Add<Position>, Option<Spawn<Add<Velocity>>>)>| {})
+        .schedule()?;
+    runner.run(&mut world)?;
+    // The roots of the `Position` segment, the `Velocity` segment and the ones that span both are applied by their
+    // own run, which only reads `Entities`.
+    let applies = runner
+        .graph()
+        .nodes
+        .into_iter()
+        .filter(|node| {
+            node.dependencies
+                .contains(&Dependency::read::<Entities>(Order::Strict))
+        })
+        .count();
+    assert_eq!(applies, 3);
+    Ok(())
+}
+
+#[test]
+fn nested_children_link_to_their_parent_and_siblings() -> Result {
+    let mut world = World::new();
+    let mut create = world.injector::<Create<(
+        Add<Position>,
+        Spawn<(Add<Position>, Spawn<Add<Position>>, Spawn<Add<Position>>)>,
+        Spawn<Add<Position>>,
+    )>>()?;
+    let mut families = world.injector::<(Families, Query<&Position>)>()?;
+
+    let root = create.run(&mut world, |mut create| {
+        let position = |x: f64| Add::new(Position(x, 0., 0.));
+        create
+            .one((
+                position(0.),
+                Spawn::new((
+                    position(1.),
+                    Spawn::new(position(3.)),
+                    Spawn::new(position(4.)),
+                )),
+                Spawn::new(position(2.)),
+            ))
+            .entity()
+    })?;
+
+    families.run(&mut world, |(families, positions)| {
+        let x = |family: Family| positions.get(family.entity()).unwrap().0;
+        let root = families.family(root);
+        let children: Vec<_> = root.children().collect();
+        assert_eq!(
+            children.iter().map(|&child| x(child)).collect::<Vec<_>>(),
+            [1., 2.]
+        );
+        assert_eq!(children[0].siblings().map(x).collect::<Vec<_>>(), [2.]);
+        assert_eq!(children[1].children().count(), 0);
+
+        let grandchildren: Vec<_> = children[0].children().collect();
+        assert_eq!(
+            grandchildren
+                .iter()
+                .map(|&child| x(child))
+                .collect::<Vec<_>>(),
+            [3., 4.]
+        );
+        assert_eq!(grandchildren[1].siblings().map(x).collect::<Vec<_>>(), [3.]);
+        for grandchild in grandchildren {
+            assert_eq!(
+                grandchild.parent().map(|parent| parent.entity()),
+                Some(children[0].entity())
+            );
+            assert_eq!(grandchild.root().entity(), root.entity());
+        }
+        assert_eq!(root.descendants().count(), 4);
+    })?;
+    Ok(())
+}
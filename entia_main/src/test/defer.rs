@@ -0,0 +1,113 @@
+use super::*;
+use entia::{
+    core::FullIterator,
+    defer::operation::{Deferred, Operation},
+    entities::Entities,
+    resource::{Read, Write},
+};
+
+#[derive(Default)]
+pub struct Swap;
+
+/// Records whether each deferred entity exists at the time of the resolution.
+#[derive(Default)]
+pub struct Check;
+
+#[derive(Resource, Default)]
+pub struct Checks(Vec<bool>);
+
+impl Operation for Swap {
+    type Item = (Entity, Entity);
+    type Inject = Write<Entities>;
+
+    fn resolve(
+        &mut self,
+        items: impl FullIterator<Item = Self::Item>,
+        entities: &mut Entities,
+    ) -> Result {
+        for (left, right) in items {
+            if let (Some(left_parent), Some(right_parent)) =
+                (entities.parent(left), entities.parent(right))
+            {
+                entities.adopt_last(right_parent, left);
+                entities.adopt_last(left_parent, right);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Operation for Check {
+    type Item = Entity;
+    type Inject = (Write<Checks>, Read<Entities>);
+
+    fn resolve(
+        &mut self,
+        items: impl FullIterator<Item = Self::Item>,
+        (checks, entities): (&mut Checks, &Entities),
+    ) -> Result {
+        checks.0.extend(items.map(|entity| entities.has(entity)));
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_operation_resolves_with_its_injection() -> Result {
+    let mut world = World::new();
+    let mut create = world.injector::<Create<_>>()?;
+    let (left, right) = create.run(&mut world, |mut create| {
+        let roots = create.all([
+            Spawn::new(Add::new(Position(0., 0., 0.))),
+            Spawn::new(Add::new(Position(0., 0., 0.))),
+        ]);
+        let left = roots.get(0).unwrap();
+        let right = roots.get(1).unwrap();
+        (
+            (left.entity(), left.children().next().unwrap().entity()),
+            (right.entity(), right.children().next().unwrap().entity()),
+        )
+    })?;
+
+    let mut swap = world.injector::<Deferred<Swap>>()?;
+    swap.run(&mut world, |mut swap| swap.one((left.1, right.1)))?;
+    let mut families = world.injector::<Families>()?;
+    families.run(&mut world, |families| {
+        assert_eq!(
+            families
+                .family(left.1)
+                .parent()
+                .map(|family| family.entity()),
+            Some(right.0)
+        );
+        assert_eq!(
+            families
+                .family(right.1)
+                .parent()
+                .map(|family| family.entity()),
+            Some(left.0)
+        );
+    })?;
+    Ok(())
+}
+
+#[test]
+fn custom_operation_is_ordered_with_other_operations() -> Result {
+    let mut world = World::new();
+    let mut create = world.injector::<Create<_>>()?;
+    let entity = create.run(&mut world, |mut create| {
+        create.one(Add::new(Position(0., 0., 0.))).entity()
+    })?;
+
+    let mut injector = world.injector::<(Deferred<Check>, Destroy)>()?;
+    injector.run(&mut world, |(mut check, mut destroy)| {
+        check.one(entity);
+        destroy.one(entity, false);
+        check.one(entity);
+    })?;
+    let mut checks = world.injector::<&Checks>()?;
+    assert_eq!(
+        checks.run(&mut world, |checks| checks.0.clone())?,
+        [true, false]
+    );
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use super::*;
+use entia::run::Frame;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+#[derive(Message)]
+pub struct OnTimer(usize);
+
+#[test]
+fn delayed_messages_arrive_in_their_frame() -> Result {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|frame: &Frame, mut emit: Emit<OnTimer>| {
+            if frame.index() == 1 {
+                emit.after(2, OnTimer(0));
+                emit.at(5, OnTimer(1));
+                emit.after(100, OnTimer(2));
+                emit.one(OnTimer(3));
+            }
+        })
+        .add(move |frame: &Frame, receive: Receive<OnTimer>| {
+            for message in receive {
+                log.lock().push((frame.index(), message.0));
+            }
+        })
+        .schedule()?;
+
+    for _ in 0..101 {
+        runner.run(&mut world)?;
+    }
+    assert_eq!(*received.lock(), [(1, 3), (3, 0), (5, 1), (101, 2)]);
+    Ok(())
+}
+
+#[test]
+fn past_frames_emit_immediately() -> Result {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|frame: &Frame, mut emit: Emit<OnTimer>| {
+            if frame.index() == 3 {
+                emit.at(1, OnTimer(0));
+                emit.after(0, OnTimer(1));
+            }
+        })
+        .add(move |frame: &Frame, receive: Receive<OnTimer>| {
+            for message in receive {
+                log.lock().push((frame.index(), message.0));
+            }
+        })
+        .schedule()?;
+
+    for _ in 0..3 {
+        runner.run(&mut world)?;
+    }
+    assert_eq!(*received.lock(), [(3, 0), (3, 1)]);
+    Ok(())
+}
+
+#[test]
+fn delayed_messages_arrive_without_emitters() -> Result {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|mut emit: Emit<OnTimer>| emit.after(2, OnTimer(0)))
+        .label("emit")
+        .add(move |frame: &Frame, receive: Receive<OnTimer>| {
+            for message in receive {
+                log.lock().push((frame.index(), message.0));
+            }
+        })
+        .schedule()?;
+
+    runner.run(&mut world)?;
+    assert_eq!(runner.disable("emit"), 1);
+    for _ in 0..3 {
+        runner.run(&mut world)?;
+    }
+    assert_eq!(*received.lock(), [(3, 0)]);
+    Ok(())
+}
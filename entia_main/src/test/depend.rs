@@ -1,4 +1,9 @@
-use crate::item::Item;
+use crate::{
+    core::FullIterator,
+    defer::{Defer, Resolve},
+    depend::{Dependency, Order},
+    item::Item,
+};
 
 use super::{error::Result, *};
 
@@ -87,3 +92,39 @@ fn query_write_read() {
 fn query_write_write() {
     inject::<CreateQuery<(&mut Position, &mut Position)>>().unwrap();
 }
+
+#[derive(Default)]
+pub struct ReadTime;
+#[derive(Default)]
+pub struct WriteTime;
+
+unsafe impl Resolve for ReadTime {
+    type Item = ();
+
+    fn resolve(&mut self, _: impl FullIterator<Item = Self::Item>) -> Result {
+        Ok(())
+    }
+
+    fn depend(&self) -> Vec<Dependency> {
+        vec![Dependency::read::<Time>(Order::Strict)]
+    }
+}
+
+unsafe impl Resolve for WriteTime {
+    type Item = ();
+
+    fn resolve(&mut self, _: impl FullIterator<Item = Self::Item>) -> Result {
+        Ok(())
+    }
+
+    fn depend(&self) -> Vec<Dependency> {
+        vec![Dependency::write::<Time>(Order::Strict)]
+    }
+}
+
+#[test]
+fn defer_read_write() -> Result {
+    inject::<(Defer<ReadTime>, Defer<WriteTime>)>()?;
+    inject::<(Defer<WriteTime>, Defer<ReadTime>)>()?;
+    Ok(())
+}
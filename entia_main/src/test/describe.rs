@@ -0,0 +1,54 @@
+use super::*;
+use entia::error::Error;
+
+#[test]
+fn describe_registers_identifier() {
+    let mut world = World::new();
+    let identifier = identify();
+    assert_eq!(world.descriptions().description(identifier), None);
+    world.descriptions().describe(identifier, "boba");
+    assert_eq!(
+        world.descriptions().description(identifier).as_deref(),
+        Some("boba")
+    );
+    assert_eq!(World::new().descriptions().description(identifier), None);
+}
+
+#[test]
+fn inner_conflict_names_system_and_data() {
+    fn conflict(_: &mut Time, _: &Time) {}
+
+    let mut world = World::new();
+    let mut runner = world.scheduler().add(conflict).schedule().unwrap();
+    let error = runner.update(&mut world).err().unwrap();
+    let report = error.to_string();
+    assert!(matches!(error, Error::Conflict { .. }));
+    assert!(report.contains("::conflict::"));
+    assert!(report.contains("has conflicting dependencies"));
+    assert!(report.contains("read/write conflict on resource `Time`"));
+    assert!(report.contains("type `Time`"));
+}
+
+#[test]
+fn outer_conflict_names_both_systems() -> Result {
+    fn write(_: Query<&mut Position>) {}
+    fn read(_: Query<&Position>) {}
+
+    let mut world = World::new();
+    world.run(|mut create: Create<_>| {
+        create.one(Add::new(Position(1.0, 2.0, 3.0)));
+    })?;
+    let mut runner = world.scheduler().add(write).add(read).schedule()?;
+    runner.update(&mut world)?;
+
+    let conflicts = runner.graph().conflicts();
+    let report = conflicts
+        .into_iter()
+        .map(|error| world.describe(error).to_string())
+        .find(|report| report.contains("::read::"))
+        .unwrap();
+    assert!(report.contains("::write::"));
+    assert!(report.contains("in segment #"));
+    assert!(report.contains("store of `"));
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn graph_has_blockers_with_errors() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|_: &mut Time| {})
+        .add(|_: &Time| {})
+        .schedule()?;
+    runner.update(&mut world)?;
+
+    let graph = runner.graph();
+    let node = graph.nodes.last().unwrap();
+    assert_eq!(node.strong.len(), 1);
+    assert!(!node.strong[0].errors.is_empty());
+    assert!(node.strong[0]
+        .errors
+        .iter()
+        .all(|error| matches!(error, entia::depend::Error::ReadWriteConflict(..))));
+    assert!(graph.dot().starts_with("digraph"));
+    let json = graph.json()?;
+    assert!(json.starts_with("{\"nodes\":["));
+    assert!(json.contains("\"conflict\":\"ReadWriteConflict\""));
+    assert!(json.contains("\"Time\""));
+    assert!(!json.contains("TypeId"));
+    Ok(())
+}
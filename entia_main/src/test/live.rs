@@ -0,0 +1,112 @@
+use super::*;
+use entia::{error::Error, stage::Tick};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+type Log = Arc<Mutex<Vec<(&'static str, usize)>>>;
+
+fn counter(log: &Log, name: &'static str) -> impl FnMut(()) + Send + Sync + 'static {
+    let log = log.clone();
+    let mut count = 0;
+    move |_: ()| {
+        count += 1;
+        log.lock().push((name, count));
+    }
+}
+
+fn frame(runner: &mut Runner, world: &mut World, log: &Log) -> Result<Vec<(&'static str, usize)>> {
+    runner.run(world)?;
+    let mut log = log.lock();
+    log.sort();
+    Ok(log.drain(..).collect())
+}
+
+#[test]
+fn insert_and_remove_preserve_state() -> Result {
+    let log = Log::default();
+    let mut world = World::new();
+    let mut runner = world.scheduler().add(counter(&log, "a")).schedule()?;
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 1)]);
+
+    let system = counter(&log, "b").system(Default::default(), &mut world)?;
+    let identifier = system.identifier();
+    runner.insert("main", system)?;
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 2), ("b", 1)]);
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 3), ("b", 2)]);
+
+    assert!(runner.remove(identifier).is_some());
+    assert!(runner.remove(identifier).is_none());
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 4)]);
+    Ok(())
+}
+
+#[test]
+fn disable_and_enable_by_label() -> Result {
+    let log = Log::default();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(counter(&log, "a"))
+        .add(counter(&log, "b"))
+        .label("b")
+        .schedule()?;
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 1), ("b", 1)]);
+
+    assert_eq!(runner.disable("b"), 1);
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 2)]);
+    assert_eq!(runner.disable("missing"), 0);
+
+    assert_eq!(runner.enable("b"), 1);
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 3), ("b", 2)]);
+    Ok(())
+}
+
+#[test]
+fn insert_into_a_stage_before_the_later_ones() -> Result {
+    let log = Log::default();
+    let mut world = World::new();
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let (early, late, inserted) = (order.clone(), order.clone(), order.clone());
+    let mut runner = world
+        .scheduler()
+        .stage("early", Tick::Frame, |scheduler| {
+            scheduler.add(move |_: ()| early.lock().push("early"))
+        })
+        .stage("late", Tick::Frame, |scheduler| {
+            scheduler.add(move |_: ()| late.lock().push("late"))
+        })
+        .schedule()?;
+
+    let system =
+        (move |_: ()| inserted.lock().push("inserted")).system(Default::default(), &mut world)?;
+    runner.insert("early", system)?;
+    frame(&mut runner, &mut world, &log)?;
+    assert_eq!(*order.lock(), ["early", "inserted", "late"]);
+
+    let system = counter(&log, "a").system(Default::default(), &mut world)?;
+    assert!(matches!(
+        runner.insert("missing", system),
+        Err(Error::MissingStage { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn insert_rejects_labeled_systems() -> Result {
+    let log = Log::default();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(counter(&log, "a"))
+        .add(counter(&log, "b"))
+        .label("b")
+        .schedule()?;
+    let identifier = runner.systems()[1].identifier();
+    let system = runner.remove(identifier).unwrap();
+    assert!(matches!(
+        runner.insert("main", system),
+        Err(Error::ConstrainedSystem { .. })
+    ));
+    assert_eq!(frame(&mut runner, &mut world, &log)?, [("a", 1)]);
+    Ok(())
+}
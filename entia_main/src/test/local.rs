@@ -0,0 +1,19 @@
+use super::*;
+
+#[test]
+fn local_persists_between_runs() -> Result {
+    let mut world = World::new();
+    let mut injector = world.injector::<Local<usize>>()?;
+    for i in 0..3 {
+        injector.run(&mut world, |mut local| {
+            assert_eq!(*local, i);
+            *local += 1;
+        })?;
+    }
+
+    let mut injector = world.injector_with::<(Local<usize>, Local<usize>)>((Some(10), None))?;
+    injector.run(&mut world, |(left, right)| {
+        assert_eq!((*left, *right), (10, 0))
+    })?;
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+#[derive(Message)]
+pub struct OnHit(usize);
+
+/// `OnHit` is not `Clone` since every receiver reads the same buffer.
+#[test]
+fn receivers_share_messages() -> Result {
+    let mut world = World::new();
+    let mut all = world.injector::<Receive<OnHit>>()?;
+    let mut last = world.injector::<Receive<OnHit, keep::Last<2>>>()?;
+    let mut first = world.injector::<Receive<OnHit, keep::First<2>>>()?;
+    let mut emit = world.injector::<Emit<OnHit>>()?;
+    emit.run(&mut world, |mut emit| emit.all((0..5).map(OnHit)))?;
+
+    let all = all.run(&mut world, |receive| {
+        receive.map(|message| message.0).collect::<Vec<_>>()
+    })?;
+    let last = last.run(&mut world, |receive| {
+        receive.map(|message| message.0).collect::<Vec<_>>()
+    })?;
+    let first = first.run(&mut world, |receive| {
+        receive.map(|message| message.0).collect::<Vec<_>>()
+    })?;
+    assert_eq!(all, [0, 1, 2, 3, 4]);
+    assert_eq!(last, [3, 4]);
+    assert_eq!(first, [0, 1]);
+    Ok(())
+}
+
+#[test]
+fn unread_messages_remain() -> Result {
+    let mut world = World::new();
+    let mut receive = world.injector::<Receive<OnHit>>()?;
+    let mut emit = world.injector::<Emit<OnHit>>()?;
+    emit.run(&mut world, |mut emit| emit.all((0..4).map(OnHit)))?;
+
+    let first = receive.run(&mut world, |mut receive| {
+        receive.next().map(|message| message.0)
+    })?;
+    assert_eq!(first, Some(0));
+    emit.run(&mut world, |mut emit| emit.one(OnHit(4)))?;
+    let rest = receive.run(&mut world, |receive| {
+        receive.map(|message| message.0).collect::<Vec<_>>()
+    })?;
+    assert_eq!(rest, [1, 2, 3, 4]);
+    Ok(())
+}
+
+#[test]
+fn messages_emitted_before_a_receiver_are_not_received() -> Result {
+    let mut world = World::new();
+    let mut early = world.injector::<Receive<OnHit>>()?;
+    let mut emit = world.injector::<Emit<OnHit>>()?;
+    emit.run(&mut world, |mut emit| emit.one(OnHit(0)))?;
+    let mut late = world.injector::<Receive<OnHit>>()?;
+    emit.run(&mut world, |mut emit| emit.one(OnHit(1)))?;
+
+    assert_eq!(early.run(&mut world, |receive| receive.len())?, 2);
+    assert_eq!(late.run(&mut world, |receive| receive.len())?, 1);
+    Ok(())
+}
+
+#[test]
+fn removed_receivers_release_their_cursor() -> Result {
+    use entia::depend::{Dependency, Key};
+
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|mut emit: Emit<OnHit>| emit.one(OnHit(0)))
+        .add(|receive: Receive<OnHit>| assert_eq!(receive.len(), 1))
+        .schedule()?;
+    runner.run(&mut world)?;
+
+    let mut cursors = Vec::new();
+    for node in runner.graph().nodes {
+        for dependency in node.dependencies {
+            if let Dependency::Write(Key::At(identifier), _) = dependency {
+                let description = world.descriptions().description(identifier);
+                if description.is_some_and(|description| description.starts_with("message cursor"))
+                {
+                    cursors.push(identifier);
+                }
+            }
+        }
+    }
+    assert_eq!(cursors.len(), 1);
+
+    let identifier = runner.systems()[1].identifier();
+    drop(runner.remove(identifier));
+    runner.run(&mut world)?;
+    assert_eq!(world.descriptions().description(cursors[0]), None);
+    Ok(())
+}
+
+#[test]
+fn receivers_do_not_block_each_other() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|receive: Receive<OnHit>| assert_eq!(receive.len(), 0))
+        .add(|receive: Receive<OnHit, keep::Last<1>>| assert_eq!(receive.len(), 0))
+        .schedule()?;
+    runner.run(&mut world)?;
+
+    let graph = runner.graph();
+    assert_eq!(graph.nodes.len(), 2);
+    for node in graph.nodes {
+        assert!(node.strong.is_empty());
+        assert!(node.weak.is_empty());
+    }
+    Ok(())
+}
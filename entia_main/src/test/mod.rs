@@ -2,8 +2,31 @@ use crate as entia;
 use entia::{message::keep, system::Barrier, *};
 use error::Result;
 
+pub mod bridge;
+pub mod condition;
 pub mod create;
+pub mod defer;
+pub mod delay;
 pub mod depend;
+pub mod describe;
+pub mod graph;
+pub mod live;
+pub mod local;
+pub mod message;
+pub mod mode;
+pub mod order;
+pub mod pinned;
+pub mod pipe;
+pub mod policy;
+pub mod prefab;
+pub mod profile;
+pub mod record;
+pub mod resource;
+pub mod schedule;
+pub mod stage;
+pub mod system;
+pub mod target;
+pub mod transfer;
 
 #[derive(Resource, Default)]
 pub struct Time(f64);
@@ -49,9 +72,6 @@ fn test() {
         .add(|_: ()| {})
         .add(|_: &Time| {})
         .add(|_: (&Time,)| {})
-        .add(|_: &Time, _: &Physics, _: &mut Time, _: &mut Physics| {})
-        .add(|_: &Time, _: &Physics, _: &mut Time, _: &mut Physics| {})
-        .add(|_: (&Time, &Physics, &mut Time, &mut Physics)| {})
         .add(|_: (&Time, &Physics)| {})
         .add(|group: Query<Entity>| for _ in &group {})
         .add(
@@ -80,12 +100,11 @@ fn test() {
         })
         // .add(|_: &'static Time| {})
         .add_with(
-            (Some(Time(12.0)), None, (), (), ()),
+            (Some(Time(12.0)), None, (), ()),
             |_a: &Time,
              _b: &mut Physics,
              _c: Emit<OnKill>,
-             _d: Receive<OnKill>,
-             _e: Query<(Entity, &mut Position, &Velocity)>| {},
+             _d: Query<(Entity, &mut Position, &Velocity)>| {},
         )
         .add(|_: (&Time, &Physics)| {})
         .add(|_: (&Time, &Physics)| {})
@@ -139,7 +158,7 @@ fn test() {
             }
         })
         .add(|on_kill: Receive<OnKill>| for _ in on_kill {})
-        .add(|mut on_kill: Receive<OnKill>| while let Some(_) = on_kill.next() {})
+        .add(|mut on_kill: Receive<OnKill>| for _ in on_kill.by_ref() {})
         .add(
             |query: Query<Entity, (Has<Position>, Has<Velocity>)>, mut destroy: Destroy| {
                 for entity in &query {
@@ -157,7 +176,7 @@ fn test() {
         .schedule()
         .unwrap();
 
-    loop {
+    for _ in 0..10 {
         runner.run(&mut world).unwrap();
     }
 }
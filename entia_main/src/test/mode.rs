@@ -0,0 +1,104 @@
+use super::*;
+use entia::run::Mode;
+use parking_lot::Mutex;
+use std::{
+    sync::Arc,
+    thread::{self, ThreadId},
+};
+
+type Log = Arc<Mutex<Vec<(usize, ThreadId)>>>;
+
+fn runner(world: &mut World, log: &Log, mode: Mode) -> Result<Runner> {
+    let push = |log: &Log, index| log.lock().push((index, thread::current().id()));
+    let mut scheduler = world.scheduler();
+    for index in 0..8 {
+        let log = log.clone();
+        scheduler = scheduler.add(move |_: ()| push(&log, index));
+    }
+    let (write, read) = (log.clone(), log.clone());
+    let mut runner = scheduler
+        .add(move |_: &mut Time| push(&write, 8))
+        .add(move |_: &Time| push(&read, 9))
+        .schedule()?;
+    runner.set_mode(mode);
+    Ok(runner)
+}
+
+fn frames(mode: Mode) -> Result<Vec<Vec<usize>>> {
+    let log = Log::default();
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, mode)?;
+    let mut frames = Vec::new();
+    for _ in 0..10 {
+        runner.run(&mut world)?;
+        frames.push(log.lock().drain(..).map(|(index, _)| index).collect());
+    }
+    Ok(frames)
+}
+
+#[test]
+fn sequential_runs_in_order_on_calling_thread() -> Result {
+    let log = Log::default();
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, Mode::Sequential)?;
+    runner.run(&mut world)?;
+
+    let log = log.lock();
+    assert_eq!(
+        log.iter().map(|&(index, _)| index).collect::<Vec<_>>(),
+        (0..10).collect::<Vec<_>>()
+    );
+    assert!(log.iter().all(|&(_, id)| id == thread::current().id()));
+    Ok(())
+}
+
+#[test]
+fn shuffle_is_reproducible_and_respects_blockers() -> Result {
+    let frames = frames(Mode::Shuffle(7))?;
+    assert_eq!(frames, self::frames(Mode::Shuffle(7))?);
+    assert!(frames.iter().any(|frame| frame[..] != frames[0][..]));
+    for frame in frames.iter() {
+        let position = |index| frame.iter().position(|&value| value == index).unwrap();
+        assert_eq!(frame.len(), 10);
+        assert!(position(8) < position(9));
+    }
+    Ok(())
+}
+
+#[test]
+fn shuffle_respects_weak_blockers_across_modes() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|mut create: Create<_>| {
+            create.one(Add::new(Position(0.0, 0.0, 0.0)));
+        })
+        .add(|mut create: Create<_>| {
+            create.one(Add::new(Position(1.0, 1.0, 1.0)));
+        })
+        .schedule()?;
+    runner.update(&mut world)?;
+    assert!(runner
+        .graph()
+        .nodes
+        .iter()
+        .any(|node| !node.weak.is_empty()));
+
+    for mode in [
+        Mode::Shuffle(3),
+        Mode::Parallel,
+        Mode::Shuffle(5),
+        Mode::Sequential,
+    ] {
+        runner.set_mode(mode);
+        for _ in 0..10 {
+            runner.run(&mut world)?;
+        }
+    }
+    world
+        .injector::<Query<&Position>>()?
+        .run(&mut world, |query| {
+            assert_eq!(query.into_iter().count(), 80)
+        })?;
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use super::*;
+use entia::stage::Tick;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn constraints_replace_declaration_order() -> Result {
+    let mut world = World::new();
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let push = |value: usize| {
+        let order = order.clone();
+        move |_: &mut Time| order.lock().unwrap().push(value)
+    };
+    let mut runner = world
+        .scheduler()
+        .add(push(3))
+        .label("render")
+        .set("physics", |scheduler| scheduler.add(push(1)).add(push(2)))
+        .before("render")
+        .after("input")
+        .add(push(0))
+        .label("input")
+        .schedule()?;
+
+    runner.run(&mut world)?;
+    assert_eq!(*order.lock().unwrap(), [0, 1, 2, 3]);
+    Ok(())
+}
+
+#[test]
+fn cycle_names_the_systems() {
+    fn a(_: &Time) {}
+    fn b(_: &Time) {}
+
+    let mut world = World::new();
+    let result = world
+        .scheduler()
+        .add(a)
+        .label("a")
+        .after("b")
+        .add(b)
+        .label("b")
+        .after("a")
+        .schedule();
+
+    match result {
+        Err(error::Error::OrderCycle { systems }) => {
+            assert_eq!(systems.len(), 2);
+            assert!(systems.iter().any(|name| name.contains("::a::")));
+            assert!(systems.iter().any(|name| name.contains("::b::")));
+        }
+        _ => panic!("expected a cycle"),
+    }
+}
+
+#[test]
+fn constraint_against_stage_order_fails() {
+    fn a(_: &Time) {}
+    fn b(_: &Time) {}
+
+    let mut world = World::new();
+    let result = world
+        .scheduler()
+        .add(a)
+        .label("a")
+        .stage("late", Tick::Frame, |scheduler| {
+            scheduler.add(b).before("a")
+        })
+        .schedule();
+
+    match result {
+        Err(error::Error::StageOrder { system, label }) => {
+            assert!(system.contains("::b::"));
+            assert_eq!(label, "a");
+        }
+        _ => panic!("expected a stage order error"),
+    }
+}
+
+#[test]
+fn constraint_along_stage_order_holds() -> Result {
+    fn a(_: &Time) {}
+    fn b(_: &Time) {}
+
+    let mut world = World::new();
+    world
+        .scheduler()
+        .add(a)
+        .label("a")
+        .stage("late", Tick::Frame, |scheduler| scheduler.add(b).after("a"))
+        .schedule()?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use super::*;
+use entia::system::Policy;
+use std::{
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::{self, ThreadId},
+};
+
+pub struct Window(Rc<ThreadId>);
+
+#[test]
+fn pinned_runs_on_calling_thread() -> Result {
+    let mut world = World::new();
+    world.pin(Window(Rc::new(thread::current().id())));
+    let mut runner = world
+        .scheduler()
+        .add(|window: Pinned<Window>| assert_eq!(*window.0, thread::current().id()))
+        .add(|_: &mut Time| {})
+        .add(|window: Pinned<Window>| assert_eq!(*window.0, thread::current().id()))
+        .schedule()?;
+    for _ in 0..100 {
+        runner.run(&mut world)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn unpinned_fails() -> Result {
+    let mut world = World::new();
+    assert!(world.injector::<Pinned<Window>>().is_err());
+
+    let id = thread::current().id();
+    world.pin(Window(Rc::new(id)));
+    let mut injector = world.injector::<Pinned<Window>>()?;
+    injector.run(&mut world, |window| assert_eq!(*window.0, id))?;
+    assert!(world.unpin::<Window>().is_some());
+    assert!(injector.run(&mut world, |_| {}).is_err());
+    Ok(())
+}
+
+#[test]
+fn unpinned_skips_its_systems() -> Result {
+    let mut world = World::new();
+    world.pin(Window(Rc::new(thread::current().id())));
+    let runs = Arc::new(AtomicUsize::new(0));
+    let missing = Arc::new(AtomicUsize::new(0));
+    let (count, errors) = (runs.clone(), missing.clone());
+    let mut runner = world
+        .scheduler()
+        .add(move |_: Pinned<Window>| {
+            count.fetch_add(1, Ordering::Relaxed);
+        })
+        .policy(Policy::Continue)
+        .schedule()?;
+    runner.handle(move |_, error| {
+        assert!(matches!(error, error::Error::MissingResource { .. }));
+        errors.fetch_add(1, Ordering::Relaxed);
+    });
+
+    runner.run(&mut world)?;
+    assert!(world.unpin::<Window>().is_some());
+    for _ in 0..3 {
+        runner.run(&mut world)?;
+    }
+    assert_eq!(runs.load(Ordering::Relaxed), 1);
+    assert_eq!(missing.load(Ordering::Relaxed), 3);
+    Ok(())
+}
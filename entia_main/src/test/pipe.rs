@@ -0,0 +1,53 @@
+use super::*;
+use entia::{
+    depend::{Dependency, Order},
+    error::Error,
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+#[test]
+fn pipe_feeds_output_to_input() -> Result {
+    fn compute(time: &Time) -> f64 {
+        time.0 * 2.0
+    }
+
+    let values = Arc::new(Mutex::new(Vec::new()));
+    let log = values.clone();
+    let mut world = World::new();
+    world.run(|time: &mut Time| time.0 = 3.0)?;
+    let mut runner = world
+        .scheduler()
+        .add(compute.pipe(move |value: f64, _: &mut Physics| log.lock().push(value)))
+        .schedule()?;
+    runner.run(&mut world)?;
+    assert_eq!(*values.lock(), [6.0]);
+
+    let graph = runner.graph();
+    assert_eq!(graph.nodes.len(), 1);
+    let dependencies = &graph.nodes[0].dependencies;
+    assert!(dependencies.contains(&Dependency::read::<Time>(Order::Strict)));
+    assert!(dependencies.contains(&Dependency::write::<Physics>(Order::Strict)));
+    Ok(())
+}
+
+#[test]
+fn pipe_handles_errors() -> Result {
+    let errors = Arc::new(Mutex::new(0));
+    let count = errors.clone();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(
+            (|| -> Result { Err(Error::FailedToRun) }).pipe(move |result: Result| {
+                if result.is_err() {
+                    *count.lock() += 1;
+                }
+            }),
+        )
+        .schedule()?;
+    runner.run(&mut world)?;
+    runner.run(&mut world)?;
+    assert_eq!(*errors.lock(), 2);
+    Ok(())
+}
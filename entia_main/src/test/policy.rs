@@ -0,0 +1,186 @@
+use super::*;
+use entia::{error::Error, run::Mode, system::Policy};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+type Log = Arc<Mutex<Vec<&'static str>>>;
+
+fn runner(world: &mut World, log: &Log, errors: &Log, policy: Policy) -> Result<Runner> {
+    let (fail, read, other) = (log.clone(), log.clone(), log.clone());
+    let mut runner = world
+        .scheduler()
+        .add(move |_: &mut Time| -> Result {
+            fail.lock().push("fail");
+            Err(Error::FailedToRun)
+        })
+        .policy(policy)
+        .add(move |_: &Time| read.lock().push("read"))
+        .add(move |_: &mut Physics| other.lock().push("other"))
+        .schedule()?;
+    let errors = errors.clone();
+    runner.handle(move |name, _| {
+        assert!(!name.is_empty());
+        errors.lock().push("error");
+    });
+    Ok(runner)
+}
+
+fn frame(runner: &mut Runner, world: &mut World, log: &Log) -> (bool, Vec<&'static str>) {
+    let result = runner.run(world);
+    let mut log = log.lock();
+    log.sort();
+    (result.is_ok(), log.drain(..).collect())
+}
+
+#[test]
+fn abort_stops_the_frame() -> Result {
+    let (log, errors) = (Log::default(), Log::default());
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, &errors, Policy::Abort)?;
+    let (success, ran) = frame(&mut runner, &mut world, &log);
+    assert!(!success);
+    assert!(!ran.contains(&"read"));
+    assert_eq!(errors.lock().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn skip_skips_dependants() -> Result {
+    for mode in [Mode::Parallel, Mode::Sequential] {
+        let (log, errors) = (Log::default(), Log::default());
+        let mut world = World::new();
+        let mut runner = runner(&mut world, &log, &errors, Policy::Skip)?;
+        runner.set_mode(mode);
+        for _ in 0..3 {
+            assert_eq!(
+                frame(&mut runner, &mut world, &log),
+                (true, vec!["fail", "other"])
+            );
+        }
+        assert_eq!(errors.lock().len(), 3);
+    }
+    Ok(())
+}
+
+#[test]
+fn continue_runs_dependants() -> Result {
+    let (log, errors) = (Log::default(), Log::default());
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, &errors, Policy::Continue)?;
+    assert_eq!(
+        frame(&mut runner, &mut world, &log),
+        (true, vec!["fail", "other", "read"])
+    );
+    assert_eq!(errors.lock().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn retry_aborts_on_second_failure() -> Result {
+    let (log, errors) = (Log::default(), Log::default());
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, &errors, Policy::Retry(1))?;
+    assert_eq!(
+        frame(&mut runner, &mut world, &log),
+        (true, vec!["fail", "other"])
+    );
+    assert!(!frame(&mut runner, &mut world, &log).0);
+    assert_eq!(errors.lock().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn retry_tolerates_its_count_of_failures() -> Result {
+    let (log, errors) = (Log::default(), Log::default());
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, &errors, Policy::Retry(3))?;
+    for _ in 0..3 {
+        assert_eq!(
+            frame(&mut runner, &mut world, &log),
+            (true, vec!["fail", "other"])
+        );
+    }
+    assert!(!frame(&mut runner, &mut world, &log).0);
+    assert_eq!(errors.lock().len(), 4);
+    Ok(())
+}
+
+#[test]
+fn retry_survives_a_rebuild() -> Result {
+    let (log, errors) = (Log::default(), Log::default());
+    let mut world = World::new();
+    let mut runner = runner(&mut world, &log, &errors, Policy::Retry(1))?;
+    assert!(frame(&mut runner, &mut world, &log).0);
+    world.modify();
+    assert!(!frame(&mut runner, &mut world, &log).0);
+    Ok(())
+}
+
+#[derive(Resource, Default)]
+pub struct Mark;
+
+#[test]
+fn skip_waits_for_the_other_blockers() -> Result {
+    for mode in [Mode::Parallel, Mode::Sequential] {
+        let log = Log::default();
+        let (fail, slow, both, after) = (log.clone(), log.clone(), log.clone(), log.clone());
+        let mut world = World::new();
+        let mut runner = world
+            .scheduler()
+            .add(move |_: &mut Time| -> Result {
+                fail.lock().push("fail");
+                Err(Error::FailedToRun)
+            })
+            .policy(Policy::Skip)
+            .add(move |_: &mut Physics| {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                slow.lock().push("slow");
+            })
+            .add(move |_: &Time, _: &Physics, _: &mut Mark| both.lock().push("both"))
+            .add(move |_: &Mark| after.lock().push("after"))
+            .schedule()?;
+        runner.set_mode(mode);
+        for _ in 0..3 {
+            assert_eq!(
+                frame(&mut runner, &mut world, &log),
+                (true, vec!["fail", "slow"])
+            );
+        }
+    }
+    Ok(())
+}
+
+#[derive(Resource, Debug, PartialEq)]
+pub struct Asset(usize);
+
+#[test]
+fn continue_skips_the_system_of_a_failed_pre_run() -> Result {
+    for mode in [Mode::Parallel, Mode::Sequential] {
+        let (log, errors) = (Log::default(), Log::default());
+        let (asset, other, failures) = (log.clone(), log.clone(), errors.clone());
+        let mut world = World::new();
+        world.resources().set(Asset(0));
+        let mut runner = world
+            .scheduler()
+            .add(move |value: &mut Asset| {
+                value.0 += 1;
+                asset.lock().push("asset");
+            })
+            .policy(Policy::Continue)
+            .add(move |_: &mut Physics| other.lock().push("other"))
+            .schedule()?;
+        runner.set_mode(mode);
+        runner.handle(move |_, _| failures.lock().push("error"));
+
+        assert_eq!(
+            frame(&mut runner, &mut world, &log),
+            (true, vec!["asset", "other"])
+        );
+        assert_eq!(world.resources().remove::<Asset>(), Some(Asset(1)));
+        for _ in 0..3 {
+            assert_eq!(frame(&mut runner, &mut world, &log), (true, vec!["other"]));
+        }
+        assert_eq!(errors.lock().len(), 3);
+    }
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use super::*;
+use entia::meta::Metas;
+use entia_serialize::{
+    deserialize::Deserialize,
+    deserializer::{Deserializer, Item, Map},
+    node::{Items, Node, Pairs},
+};
+use std::result;
+
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct Health {
+    current: usize,
+    max: usize,
+}
+
+impl Deserialize for &mut Health {
+    type Value = ();
+
+    fn deserialize<D: Deserializer>(self, deserializer: D) -> result::Result<(), D::Error> {
+        let mut map = deserializer.map()?;
+        let mut key = String::new();
+        while let Some(((), item)) = map.pair(&mut key)? {
+            match key.as_str() {
+                "current" => item.value(&mut self.current)?,
+                "max" => item.value(&mut self.max)?,
+                _ => item.excess()?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn map<const N: usize>(pairs: [(&str, Node); N]) -> Node {
+    Node::Map(Pairs::new(
+        pairs
+            .into_iter()
+            .map(|(key, value)| (Node::String(key.into()), value))
+            .collect(),
+    ))
+}
+
+fn health(current: usize, max: usize) -> Node {
+    map([("current", Node::Usize(current)), ("max", Node::Usize(max))])
+}
+
+#[test]
+fn instantiate_spawns_hierarchy() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let prefab = world.prefab(&map([
+        ("components", map([("Health", health(5, 10))])),
+        (
+            "children",
+            Node::List(Items::new(vec![
+                map([
+                    ("components", map([("Health", health(1, 2))])),
+                    ("children", Node::List(Items::new(vec![map([])]))),
+                ]),
+                map([]),
+            ])),
+        ),
+    ]))?;
+
+    let mut create = world.injector::<Create<Prefab>>()?;
+    let roots = create.run(&mut world, |mut create| {
+        let families = create.clones(2, prefab.clone());
+        [
+            families.get(0).unwrap().entity(),
+            families.get(1).unwrap().entity(),
+        ]
+    })?;
+    let mut families = world.injector::<(Families, Query<&Health>)>()?;
+    families.run(&mut world, |(families, healths)| {
+        assert_eq!(families.roots().count(), 2);
+        for (root, family) in roots.into_iter().zip(families.roots()) {
+            assert_eq!(root, family.entity());
+            assert_eq!(
+                healths.get(root),
+                Some(&Health {
+                    current: 5,
+                    max: 10
+                })
+            );
+            let children: Vec<_> = family.children().map(|child| child.entity()).collect();
+            assert_eq!(children.len(), 2);
+            assert_eq!(
+                healths.get(children[0]),
+                Some(&Health { current: 1, max: 2 })
+            );
+            assert_eq!(healths.get(children[1]), None);
+            assert_eq!(family.children().next().unwrap().children().count(), 1);
+            assert_eq!(family.descendants().count(), 3);
+        }
+    })?;
+    Ok(())
+}
+
+#[test]
+fn unknown_component_fails_to_load() {
+    let mut world = World::new();
+    let result = world.prefab(&map([("components", map([("Health", health(1, 1))]))]));
+    assert!(matches!(result, Err(error::Error::MissingName { .. })));
+}
+
+#[test]
+fn overrides_apply_onto_base() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let goblin = world.prefab(&map([
+        ("components", map([("Health", health(5, 10))])),
+        ("children", Node::List(Items::new(vec![map([])]))),
+    ]))?;
+    world.register_prefab("goblin", &goblin)?;
+    let chief = world.prefab(&map([
+        ("base", Node::String("goblin".into())),
+        (
+            "overrides",
+            map([("Health", map([("max", Node::Usize(20))]))]),
+        ),
+    ]))?;
+
+    let mut create = world.injector::<Create<Prefab>>()?;
+    let (goblin, chief) = create.run(&mut world, |mut create| {
+        (create.one(goblin).entity(), create.one(chief).entity())
+    })?;
+    let mut families = world.injector::<(Families, Query<&Health>)>()?;
+    families.run(&mut world, |(families, healths)| {
+        assert_eq!(
+            healths.get(goblin),
+            Some(&Health {
+                current: 5,
+                max: 10
+            })
+        );
+        assert_eq!(
+            healths.get(chief),
+            Some(&Health {
+                current: 5,
+                max: 20
+            })
+        );
+        for root in families.roots() {
+            assert_eq!(root.children().count(), 1);
+        }
+    })?;
+    Ok(())
+}
+
+#[test]
+fn unknown_field_fails_to_load() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let goblin = world.prefab(&map([("components", map([("Health", health(5, 10))]))]))?;
+    world.register_prefab("goblin", &goblin)?;
+    let result = world.prefab(&map([
+        ("base", Node::String("goblin".into())),
+        (
+            "overrides",
+            map([("Health", map([("armor", Node::Usize(1))]))]),
+        ),
+    ]));
+    assert!(matches!(result, Err(error::Error::UnknownField { .. })));
+    let result = world.prefab(&map([("base", Node::String("orc".into()))]));
+    assert!(matches!(result, Err(error::Error::MissingPrefab { .. })));
+    Ok(())
+}
+
+#[test]
+fn prefab_combines_with_other_templates() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let goblin = world.prefab(&map([("components", map([("Health", health(5, 10))]))]))?;
+
+    let mut create = world.injector::<Create<(Prefab, Spawn<Add<Position>>)>>()?;
+    let root = create.run(&mut world, |mut create| {
+        let position = Add::new(Position(1.0, 2.0, 3.0));
+        create.one((goblin.clone(), Spawn::new(position))).entity()
+    })?;
+    let mut families = world.injector::<(Families, Query<&Health>, Query<&Position>)>()?;
+    families.run(&mut world, |(families, healths, positions)| {
+        let root = families
+            .roots()
+            .find(|family| family.entity() == root)
+            .unwrap();
+        assert_eq!(
+            healths.get(root.entity()),
+            Some(&Health {
+                current: 5,
+                max: 10
+            })
+        );
+        let children: Vec<_> = root.children().map(|child| child.entity()).collect();
+        assert_eq!(children.len(), 1);
+        assert!(positions.get(children[0]).is_some());
+    })?;
+
+    let result = world.injector::<Create<(Add<Position>, Prefab)>>();
+    assert!(matches!(result, Err(error::Error::PrefabMustOwnEntity)));
+    Ok(())
+}
+
+#[test]
+fn prefab_of_another_world_is_rejected() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let mut other = World::new();
+    other.register::<Health>("Health")?;
+    let goblin = other.prefab(&map([("components", map([("Health", health(5, 10))]))]))?;
+    let result = world.register_prefab("goblin", &goblin);
+    assert!(matches!(result, Err(error::Error::WrongWorld { .. })));
+
+    let mut create = world.injector::<Create<Prefab>>()?;
+    let result = create.run(&mut world, |mut create| {
+        create.all([goblin]).roots().count()
+    });
+    assert!(matches!(result, Err(error::Error::WrongWorld { .. })));
+    Ok(())
+}
+
+#[test]
+fn prefab_loaded_after_its_create_is_rejected() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let mut create = world.injector::<Create<Prefab>>()?;
+    let goblin = world.prefab(&map([("components", map([("Health", health(5, 10))]))]))?;
+    let result = create.run(&mut world, |mut create| {
+        create.all([goblin.clone()]).roots().count()
+    });
+    assert!(matches!(result, Err(error::Error::UndeclaredPrefab)));
+
+    let mut create = world.injector::<Create<Prefab>>()?;
+    let count = create.run(&mut world, |mut create| {
+        create.all([goblin]).roots().count()
+    })?;
+    assert_eq!(count, 1);
+    let mut healths = world.injector::<Query<&Health>>()?;
+    assert_eq!(
+        healths.run(&mut world, |healths| healths.into_iter().count())?,
+        1
+    );
+    Ok(())
+}
+
+#[test]
+fn dropped_prefabs_release_their_shape() -> Result {
+    let mut world = World::new();
+    world.register::<Health>("Health")?;
+    let goblin = world.prefab(&map([("components", map([("Health", health(5, 10))]))]))?;
+    let orc = world.prefab(&map([("components", map([("Health", health(8, 8))]))]))?;
+    let mut metas = world.injector::<&Metas>()?;
+    assert_eq!(metas.run(&mut world, |metas| metas.shapes().count())?, 1);
+
+    drop(goblin);
+    assert_eq!(metas.run(&mut world, |metas| metas.shapes().count())?, 1);
+    drop(orc);
+    assert_eq!(metas.run(&mut world, |metas| metas.shapes().count())?, 0);
+    Ok(())
+}
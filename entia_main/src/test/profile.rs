@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn profiler_records_runs_and_exports_trace() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|time: &mut Time| time.0 += 1.0)
+        .add(|_: &Time| {})
+        .schedule()?;
+    runner.profile(true);
+
+    for _ in 0..3 {
+        runner.run(&mut world)?;
+    }
+
+    let profiler = runner.profiler().unwrap();
+    assert_eq!(profiler.frame(), 3);
+    let summary = profiler.summary();
+    assert_eq!(summary.len(), 2);
+    assert!(summary.iter().all(|summary| summary.count == 3));
+    let trace = profiler.trace()?;
+    assert!(trace.starts_with("{\"traceEvents\":["));
+    assert!(trace.contains("\"ph\":\"X\""));
+    Ok(())
+}
+
+#[test]
+fn profiler_names_events_of_removed_systems() -> Result {
+    fn first(time: &mut Time) {
+        time.0 += 1.0;
+    }
+    fn second(_: &Time) {}
+
+    let mut world = World::new();
+    let mut runner = world.scheduler().add(first).add(second).schedule()?;
+    runner.profile(true);
+    runner.run(&mut world)?;
+    let identifier = runner.systems()[0].identifier();
+    runner.remove(identifier);
+    runner.run(&mut world)?;
+
+    let summary = runner.profiler().unwrap().summary();
+    assert_eq!(summary.len(), 2);
+    assert!(summary[0].system.contains("first"));
+    assert_eq!(summary[0].count, 1);
+    assert!(summary[1].system.contains("second"));
+    assert_eq!(summary[1].count, 2);
+    Ok(())
+}
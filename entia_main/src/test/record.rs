@@ -0,0 +1,118 @@
+use super::*;
+use entia::message::record::Replay;
+use entia::run::Frame;
+use entia_serialize::{
+    deserialize::Deserialize,
+    deserializer::Deserializer,
+    node::{deserialize::NodeDeserializer, serialize::NodeSerializer},
+    serialize::Serialize,
+    serializer::Serializer,
+};
+use parking_lot::Mutex;
+use std::{result, sync::Arc};
+
+#[derive(Message, Debug, Default, PartialEq)]
+pub struct OnInput(usize);
+
+impl Serialize for OnInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Value, S::Error> {
+        serializer.usize(self.0)
+    }
+}
+
+impl Deserialize for &mut OnInput {
+    type Value = ();
+
+    fn deserialize<D: Deserializer>(self, deserializer: D) -> result::Result<(), D::Error> {
+        self.0 = deserializer.usize()?;
+        Ok(())
+    }
+}
+
+/// Runs 4 frames where the emitter emits in frames 1 and 3 and returns what the receiver received.
+fn session(world: &mut World, offset: usize) -> Result<Vec<(usize, usize)>> {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    let mut runner = world
+        .scheduler()
+        .add(move |frame: &Frame, mut emit: Emit<OnInput>| {
+            if frame.index() % 2 == 1 {
+                emit.one(OnInput(frame.index() * 10 + offset));
+            }
+        })
+        .add(move |frame: &Frame, receive: Receive<OnInput>| {
+            for message in receive {
+                log.lock().push((frame.index(), message.0));
+            }
+        })
+        .schedule()?;
+    for _ in 0..4 {
+        runner.run(world)?;
+    }
+    let received = received.lock().clone();
+    Ok(received)
+}
+
+#[test]
+fn replay_reproduces_recorded_messages() -> Result {
+    let mut world = World::new();
+    world.record::<OnInput>();
+    let live = session(&mut world, 0)?;
+    assert_eq!(live, [(1, 10), (3, 30)]);
+
+    let recording = world.take_recording::<OnInput>()?.unwrap();
+    assert!(world.take_recording::<OnInput>()?.is_none());
+    let node = recording.serialize(NodeSerializer).ok().unwrap();
+    let mut replay = Replay::<OnInput>::default();
+    (&mut replay)
+        .deserialize(NodeDeserializer(node))
+        .ok()
+        .unwrap();
+    assert_eq!(replay.0, [(1, OnInput(10)), (3, OnInput(30))]);
+
+    let mut world = World::new();
+    world.replay(replay);
+    assert_eq!(session(&mut world, 1)?, live);
+    Ok(())
+}
+
+#[test]
+fn resume_restores_live_emitters() -> Result {
+    let mut world = World::new();
+    world.replay([(2, OnInput(0))]);
+    world.resume::<OnInput>();
+    assert_eq!(session(&mut world, 1)?, [(1, 11), (3, 31)]);
+    Ok(())
+}
+
+#[test]
+fn resume_keeps_live_delayed_messages() -> Result {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = received.clone();
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|frame: &Frame, mut emit: Emit<OnInput>| {
+            if frame.index() == 1 {
+                emit.after(1, OnInput(0));
+                emit.after(3, OnInput(1));
+            }
+        })
+        .add(move |frame: &Frame, receive: Receive<OnInput>| {
+            for message in receive {
+                log.lock().push((frame.index(), message.0));
+            }
+        })
+        .schedule()?;
+
+    runner.run(&mut world)?;
+    world.replay([(3, OnInput(2)), (5, OnInput(3))]);
+    runner.run(&mut world)?;
+    runner.run(&mut world)?;
+    world.resume::<OnInput>();
+    for _ in 0..2 {
+        runner.run(&mut world)?;
+    }
+    assert_eq!(*received.lock(), [(3, 2), (4, 1)]);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+#[derive(Resource, Debug, PartialEq)]
+pub struct Pack(usize);
+
+#[derive(Resource, Debug, PartialEq)]
+pub struct Name(String);
+
+#[test]
+fn missing_resource_fails() {
+    let mut world = World::new();
+    assert!(matches!(
+        world.injector::<&Pack>(),
+        Err(error::Error::MissingResource { .. })
+    ));
+}
+
+#[test]
+fn optional_resource_follows_resources() -> Result {
+    let mut world = World::new();
+    let mut injector = world.injector::<Option<&Pack>>()?;
+    assert!(injector.run(&mut world, |pack| pack.is_none())?);
+
+    world.resources().set(Pack(1));
+    assert!(injector.run(&mut world, |pack| pack == Some(&Pack(1)))?);
+
+    let mut required = world.injector::<&mut Pack>()?;
+    required.run(&mut world, |pack| pack.0 += 1)?;
+    assert_eq!(world.resources().remove::<Pack>(), Some(Pack(2)));
+    assert!(injector.run(&mut world, |pack| pack.is_none())?);
+    assert!(required.run(&mut world, |_| {}).is_err());
+    Ok(())
+}
+
+#[test]
+fn removed_resource_outlives_its_holders() -> Result {
+    let mut world = World::new();
+    world.resources().set(Name("a".into()));
+    let mut injector = world.injector::<Option<&Name>>()?;
+    assert_eq!(world.resources().remove::<Name>(), Some(Name("a".into())));
+
+    world.resources().set(Name("b".into()));
+    let name = injector.run(&mut world, |name| name.map(|name| name.0.clone()))?;
+    assert_eq!(name, Some("b".into()));
+    drop(injector);
+    assert_eq!(world.resources().remove::<Name>(), Some(Name("b".into())));
+    Ok(())
+}
+
+pub struct Manual(usize);
+
+impl Resource for Manual {}
+
+#[test]
+fn manual_resource_uses_the_default_meta() -> Result {
+    let mut world = World::new();
+    assert!(world.injector::<&Manual>().is_err());
+    world.resources().set(Manual(1));
+    let mut injector = world.injector::<&mut Manual>()?;
+    injector.run(&mut world, |manual| manual.0 += 1)?;
+    assert_eq!(
+        world.resources().get::<Manual>().map(|manual| manual.0),
+        Some(2)
+    );
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use super::*;
+use entia::{
+    depend::{Conflict, Order},
+    graph::Graph,
+};
+
+/// Compares the blockers of the graph with the conflicts of all pairs of runs.
+fn check(graph: &Graph) {
+    let mut conflict = Conflict::default();
+    for node in graph.nodes.iter() {
+        conflict.detect_inner(&node.dependencies, true).unwrap();
+        for other in graph.nodes[..node.index].iter() {
+            let weak = node.weak.iter().any(|edge| edge.blocker == other.index);
+            match conflict.detect_outer(&other.dependencies, true) {
+                Ok(Order::Strict) => assert!(!weak),
+                Ok(Order::Relax) => assert!(weak),
+                Err(_) => assert!(reaches(graph, node.index, other.index)),
+            }
+        }
+    }
+}
+
+fn reaches(graph: &Graph, from: usize, to: usize) -> bool {
+    from == to
+        || graph.nodes[from]
+            .strong
+            .iter()
+            .any(|edge| reaches(graph, edge.blocker, to))
+}
+
+#[test]
+fn schedule_orders_all_conflicts() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|_: &Time| {})
+        .add(|_: &mut Time| {})
+        .add(|_: &Time, _: &Physics| {})
+        .add(|_: &Time| {})
+        .add(|_: Query<&mut Position>| {})
+        .add(|_: &mut Physics| {})
+        .add(|_: Query<(&Position, &Velocity)>, _: Create<Add<Position>>| {})
+        .add(|_: &mut Time, _: &Physics| {})
+        .add(Barrier)
+        .add(|_: Query<&mut Velocity>, _: &Time| {})
+        .add(|_: Query<Entity>, _: Destroy| {})
+        .add(|_: &mut Time| {})
+        .schedule()?;
+    runner.update(&mut world)?;
+    check(&runner.graph());
+    Ok(())
+}
+
+#[test]
+fn schedule_blocks_on_last_writer() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|_: &mut Time| {})
+        .add(|_: &mut Time| {})
+        .add(|_: &mut Time| {})
+        .add(|_: &Time| {})
+        .schedule()?;
+    runner.update(&mut world)?;
+
+    let graph = runner.graph();
+    let blockers = |index: usize| {
+        graph.nodes[index]
+            .strong
+            .iter()
+            .map(|edge| edge.blocker)
+            .collect::<Vec<_>>()
+    };
+    let offset = graph.nodes.len() - 4;
+    assert_eq!(blockers(offset + 3), [offset + 2]);
+    assert_eq!(blockers(offset + 2), [offset + 1]);
+    Ok(())
+}
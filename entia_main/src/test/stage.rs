@@ -0,0 +1,67 @@
+use super::*;
+use entia::stage::{Stage, Tick};
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+pub struct Counts {
+    frame: usize,
+    fixed: usize,
+    every: usize,
+}
+
+#[test]
+fn stages_run_according_to_their_tick() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .add(|counts: &mut Counts| counts.frame += 1)
+        .stage(
+            "physics",
+            Tick::fixed(Duration::from_millis(10), 4),
+            |scheduler| scheduler.add(|counts: &mut Counts| counts.fixed += 1),
+        )
+        .stage("ai", Tick::Frames(2), |scheduler| {
+            scheduler.add(|counts: &mut Counts| counts.every += 1)
+        })
+        .schedule()?;
+    assert_eq!(runner.stages().len(), 3);
+
+    runner.run_for(&mut world, Duration::from_millis(25))?;
+    runner.run_for(&mut world, Duration::from_millis(25))?;
+    // Only 4 steps are caught up and the excess time is dropped.
+    runner.run_for(&mut world, Duration::from_millis(100))?;
+    runner.run_for(&mut world, Duration::from_millis(5))?;
+    world.injector::<&Counts>()?.run(&mut world, |counts| {
+        assert_eq!(counts.frame, 4);
+        assert_eq!(counts.fixed, 9);
+        assert_eq!(counts.every, 2);
+    })?;
+    Ok(())
+}
+
+#[test]
+fn resumed_stage_shares_its_tick() -> Result {
+    let mut world = World::new();
+    let mut runner = world
+        .scheduler()
+        .stage("ai", Tick::Frames(2), |scheduler| {
+            scheduler
+                .add(|counts: &mut Counts| counts.every += 1)
+                .stage("physics", Tick::Frame, |scheduler| {
+                    scheduler.add(|counts: &mut Counts| counts.frame += 1)
+                })
+                .add(|counts: &mut Counts| counts.every += 1)
+        })
+        .schedule()?;
+    let names: Vec<_> = runner.stages().iter().map(Stage::name).collect();
+    assert_eq!(names, ["ai", "physics"]);
+
+    for _ in 0..4 {
+        runner.run(&mut world)?;
+    }
+    world.injector::<&Counts>()?.run(&mut world, |counts| {
+        assert_eq!(counts.frame, 4);
+        assert_eq!(counts.every, 4);
+    })?;
+    Ok(())
+}
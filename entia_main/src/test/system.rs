@@ -0,0 +1,32 @@
+use super::*;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[derive(Resource, Default)]
+pub struct Count(usize);
+
+#[test]
+fn exclusive_reschedules_remaining_systems() -> Result {
+    let mut world = World::new();
+    let seen = Arc::new(AtomicUsize::new(0));
+    let counter = seen.clone();
+    let mut runner = world
+        .scheduler()
+        .add(|count: &mut Count| count.0 += 1)
+        .add(|world: &mut World| {
+            let count = world.resources().remove::<Count>().unwrap();
+            world.resources().set(Count(count.0 * 10));
+        })
+        .add(move |count: Option<&Count>| {
+            counter.store(count.map_or(0, |count| count.0), Ordering::Relaxed)
+        })
+        .schedule()?;
+
+    runner.run(&mut world)?;
+    assert_eq!(seen.load(Ordering::Relaxed), 10);
+    runner.run(&mut world)?;
+    assert_eq!(seen.load(Ordering::Relaxed), 110);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use super::*;
+use entia::{item::Item, message::target::Delivery};
+
+#[derive(Message)]
+pub struct OnClick;
+
+/// Creates a root with a `Position`, a child with a `Velocity` and a grandchild with a `Position`.
+fn hierarchy(world: &mut World) -> Result<(Entity, Entity, Entity)> {
+    let mut create = world.injector::<Create<_>>()?;
+    let (root, child) = create.run(world, |mut create| {
+        let family = create.one((
+            Add::new(Position(0., 0., 0.)),
+            Spawn::new(Add::new(Velocity(0., 0., 0.))),
+        ));
+        let child = family.children().next().unwrap().entity();
+        (family.entity(), child)
+    })?;
+    let mut create = world.injector::<Create<_>>()?;
+    let grandchild = create.run(world, |mut create| {
+        create.one(Add::new(Position(0., 0., 0.))).entity()
+    })?;
+    let mut adopt = world.injector::<Adopt>()?;
+    adopt.run(world, |mut adopt| adopt.last(child, grandchild))?;
+    Ok((root, child, grandchild))
+}
+
+fn deliver<I: Item + 'static>(
+    world: &mut World,
+    receive: &mut Injector<(Receive<Target<OnClick>>, Query<I>)>,
+    stop: Option<Entity>,
+) -> Result<Vec<Entity>> {
+    receive.run(world, |(receive, query)| {
+        receive
+            .on(&query)
+            .inspect(|delivery: &Delivery<_>| {
+                if stop == Some(delivery.entity()) {
+                    delivery.stop()
+                }
+            })
+            .map(|delivery| delivery.entity())
+            .collect()
+    })
+}
+
+#[test]
+fn targeted_messages_reach_matched_entities() -> Result {
+    let mut world = World::new();
+    let (_, child, grandchild) = hierarchy(&mut world)?;
+    let mut positions = world.injector::<(Receive<Target<OnClick>>, Query<&Position>)>()?;
+    let mut velocities = world.injector::<(Receive<Target<OnClick>>, Query<&Velocity>)>()?;
+    let mut emit = world.injector::<Emit<Target<OnClick>>>()?;
+    emit.run(&mut world, |mut emit| {
+        emit.to(grandchild, OnClick);
+        emit.to(child, OnClick);
+    })?;
+
+    assert_eq!(deliver(&mut world, &mut positions, None)?, [grandchild]);
+    assert_eq!(deliver(&mut world, &mut velocities, None)?, [child]);
+    Ok(())
+}
+
+#[test]
+fn bubbling_messages_reach_ancestors_until_stopped() -> Result {
+    let mut world = World::new();
+    let (root, child, grandchild) = hierarchy(&mut world)?;
+    let mut velocities = world.injector::<(Receive<Target<OnClick>>, Query<&Velocity>)>()?;
+    let mut positions = world.injector::<(Receive<Target<OnClick>>, Query<&Position>)>()?;
+    let mut emit = world.injector::<Emit<Target<OnClick>>>()?;
+
+    emit.run(&mut world, |mut emit| emit.bubble(grandchild, OnClick))?;
+    assert_eq!(deliver(&mut world, &mut velocities, None)?, [child]);
+    assert_eq!(
+        deliver(&mut world, &mut positions, None)?,
+        [grandchild, root]
+    );
+
+    emit.run(&mut world, |mut emit| emit.bubble(grandchild, OnClick))?;
+    assert_eq!(deliver(&mut world, &mut velocities, Some(child))?, [child]);
+    assert_eq!(deliver(&mut world, &mut positions, None)?, [grandchild]);
+    Ok(())
+}
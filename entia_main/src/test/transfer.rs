@@ -0,0 +1,108 @@
+use super::*;
+use entia::meta::{Meta, Remapper};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Target(Entity);
+
+impl Component for Target {
+    fn meta() -> Meta {
+        let mut meta = entia::meta!(Self);
+        meta.set(Remapper::new(|target: &mut Target, remap| {
+            target.0 = remap.entity(target.0)
+        }));
+        meta
+    }
+}
+
+#[test]
+fn transfer_preserves_families() -> Result {
+    let mut source = World::new();
+    let mut target = World::new();
+    let mut create = source.injector::<Create<_>>()?;
+    let root = create.run(&mut source, |mut create| {
+        create
+            .one((
+                Add::new(Position(1., 2., 3.)),
+                Spawn::new(Add::new(Position(4., 5., 6.))),
+                Spawn::new(Add::new(Velocity(7., 8., 9.))),
+            ))
+            .entity()
+    })?;
+    let version = source.version();
+    let remap = source.transfer(&mut target, [root], true)?;
+    assert_eq!(remap.len(), 3);
+    assert!(source.version() > version);
+
+    let mut query = source.injector::<Query<Entity>>()?;
+    query.run(&mut source, |query| {
+        assert_eq!(query.into_iter().count(), 0)
+    })?;
+
+    let mut families = target.injector::<Families>()?;
+    families.run(&mut target, |families| {
+        let roots: Vec<_> = families.roots().collect();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].entity(), remap.entity(root));
+        assert_eq!(roots[0].children().count(), 2);
+    })?;
+
+    let mut query = target.injector::<Query<(&Position, Option<&Velocity>)>>()?;
+    query.run(&mut target, |query| {
+        let root = query.get(remap.entity(root)).unwrap();
+        assert_eq!(root.0, &Position(1., 2., 3.));
+        assert_eq!(query.into_iter().count(), 2);
+    })?;
+    Ok(())
+}
+
+#[test]
+fn transfer_moves_many_entities() -> Result {
+    let mut source = World::new();
+    let mut target = World::new();
+    let mut create = source.injector::<Create<_>>()?;
+    let entities = create.run(&mut source, |mut create| {
+        Iterator::map(0..100, |index| {
+            create
+                .one(Add::new(Position(index as f64, 0., 0.)))
+                .entity()
+        })
+        .collect::<Vec<_>>()
+    })?;
+    let remap = source.transfer(&mut target, entities.iter().copied(), false)?;
+    assert_eq!(remap.len(), 100);
+
+    let mut query = target.injector::<Query<&Position>>()?;
+    query.run(&mut target, |query| {
+        assert_eq!(query.into_iter().count(), 100);
+        for (index, &entity) in entities.iter().enumerate() {
+            assert_eq!(
+                query.get(remap.entity(entity)),
+                Some(&Position(index as f64, 0., 0.))
+            );
+        }
+    })?;
+    Ok(())
+}
+
+#[test]
+fn merge_remaps_entities() -> Result {
+    let mut source = World::new();
+    let mut target = World::new();
+    let mut create_position = source.injector::<Create<_>>()?;
+    let mut create_target = source.injector::<Create<_>>()?;
+    let first = create_position.run(&mut source, |mut create| {
+        create.one(Add::new(Position(0., 0., 0.))).entity()
+    })?;
+    let second = create_target.run(&mut source, |mut create| {
+        create.one(Add::new(Target(first))).entity()
+    })?;
+    let remap = target.merge(source)?;
+    assert_eq!(remap.len(), 2);
+
+    let mut query = target.injector::<Query<&Target>>()?;
+    query.run(&mut target, |query| {
+        let item = query.get(remap.entity(second)).unwrap();
+        assert_eq!(item, &Target(remap.entity(first)));
+    })?;
+    Ok(())
+}
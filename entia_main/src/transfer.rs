@@ -0,0 +1,284 @@
+use crate::{
+    entities::{Datum, Entities},
+    entity::Entity,
+    error::{Error, Result},
+    meta::Metas,
+    segment::Segments,
+    store::Store,
+    world::World,
+};
+use std::{
+    collections::{hash_map, HashMap, HashSet},
+    sync::Arc,
+};
+
+/// Maps the entities of a source `World` to their counterpart in the target `World` after a transfer.
+/// Components that hold `Entity` values may use it through a `meta::Remapper` module to fix their references.
+#[derive(Debug, Default, Clone)]
+pub struct Remap(HashMap<Entity, Entity>);
+
+struct Parts(Arc<Store>, Arc<Store>, Arc<Store>);
+
+impl Remap {
+    #[inline]
+    pub fn get(&self, entity: Entity) -> Option<Entity> {
+        self.0.get(&entity).copied()
+    }
+
+    /// Returns the remapped entity or `Entity::NULL` if the entity was not transferred.
+    #[inline]
+    pub fn entity(&self, entity: Entity) -> Entity {
+        self.get(entity).unwrap_or(Entity::NULL)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> hash_map::Iter<'_, Entity, Entity> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Remap {
+    type Item = (&'a Entity, &'a Entity);
+    type IntoIter = hash_map::Iter<'a, Entity, Entity>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Parts {
+    fn new(world: &mut World) -> Result<Self> {
+        let resources = world.resources();
+        unsafe {
+            // 'Entities' must be retrieved first such that 'Metas' is guaranteed to exist when retrieved.
+            let entities = resources.get_store::<Entities>(None)?;
+            let segments = resources.get_store::<Segments>(None)?;
+            let metas = resources.get_store::<Metas>(None)?;
+            Ok(Self(entities, segments, metas))
+        }
+    }
+
+    #[inline]
+    fn get(&mut self) -> (&mut Entities, &mut Segments, &mut Metas) {
+        unsafe { (self.0.get(0), self.1.get(0), self.2.get(0)) }
+    }
+}
+
+impl World {
+    /// Moves the `entities` (and their descendants if `descendants` is `true`) with all their components from this
+    /// `World` to the `target`. Family links between transferred entities are preserved while links to entities
+    /// that remain in this `World` are rejected. Returns the mapping of the transferred entities.
+    pub fn transfer<E: Into<Entity>>(
+        &mut self,
+        target: &mut World,
+        entities: impl IntoIterator<Item = E>,
+        descendants: bool,
+    ) -> Result<Remap> {
+        let mut source_parts = Parts::new(self)?;
+        let mut target_parts = Parts::new(target)?;
+        let (source_entities, source_segments, _) = source_parts.get();
+        let (target_entities, target_segments, target_metas) = target_parts.get();
+
+        let mut set = HashSet::new();
+        let mut sources = Vec::new();
+        for entity in entities {
+            let entity = entity.into();
+            if source_entities.has(entity) && set.insert(entity) {
+                sources.push(entity);
+            }
+            if descendants {
+                source_entities.descend(
+                    entity,
+                    |child| {
+                        if set.insert(child) {
+                            sources.push(child);
+                        }
+                    },
+                    |_| {},
+                );
+            }
+        }
+
+        // Family links are collected before they are modified such that the order of children is preserved.
+        let children: Vec<Vec<_>> = sources
+            .iter()
+            .map(|&entity| {
+                source_entities
+                    .children(entity)
+                    .filter(|child| set.contains(child))
+                    .collect()
+            })
+            .collect();
+        for &entity in sources.iter() {
+            if let Some(parent) = source_entities.parent(entity) {
+                if !set.contains(&parent) {
+                    source_entities.reject(entity);
+                }
+            }
+
+            let remain: Vec<_> = source_entities
+                .children(entity)
+                .filter(|child| !set.contains(child))
+                .collect();
+            for child in remain {
+                source_entities.reject(child);
+            }
+        }
+
+        let mut targets = vec![Entity::NULL; sources.len()];
+        target_entities.reserve(&mut targets);
+        target_entities.resolve();
+
+        // Target slots are reserved once per segment such that each segment grows at most once.
+        let count = target_segments.len();
+        let mut indices = HashMap::new();
+        let mut segments = Vec::with_capacity(sources.len());
+        let mut counts = HashMap::<usize, usize>::new();
+        for &source in sources.iter() {
+            let datum = source_entities
+                .get_datum(source)
+                .expect("Entity must be valid.");
+            let segment = match indices.get(&datum.segment) {
+                Some(&segment) => segment,
+                None => {
+                    let metas: Vec<_> = source_segments[datum.segment as usize]
+                        .metas()
+                        .map(|meta| target_metas.get_or_insert(meta))
+                        .collect();
+                    let segment = target_segments.get_or_add(metas, target_metas).index();
+                    indices.insert(datum.segment, segment);
+                    segment
+                }
+            };
+            segments.push(segment);
+            *counts.entry(segment).or_default() += 1;
+        }
+        let mut next: HashMap<_, _> = counts
+            .into_iter()
+            .map(|(segment, count)| {
+                let target_segment = &mut target_segments[segment];
+                let (store, _) = target_segment.reserve(count);
+                target_segment.resolve();
+                (segment, store)
+            })
+            .collect();
+
+        let mut map = HashMap::with_capacity(sources.len());
+        let mut rows = Vec::with_capacity(sources.len());
+        for ((&source, &target), &segment) in
+            sources.iter().zip(targets.iter()).zip(segments.iter())
+        {
+            // The datum is retrieved again since removing previous entities may have moved this one.
+            let datum = source_entities
+                .get_datum(source)
+                .cloned()
+                .expect("Entity must be valid.");
+            let index = next.get_mut(&segment).expect("Segment must be reserved.");
+            let store = *index;
+            *index += 1;
+
+            let source_segment = &mut source_segments[datum.segment as usize];
+            let target_segment = &mut target_segments[segment];
+            for source_store in source_segment.stores() {
+                if source_store.meta().is::<Entity>() {
+                    continue;
+                }
+
+                let target_store = target_segment.store(source_store.meta().identifier())?;
+                unsafe {
+                    Store::copy(
+                        (source_store, datum.store as usize),
+                        (target_store, store),
+                        1,
+                    )
+                };
+            }
+            unsafe { target_segment.entity_store().set(store, target) };
+
+            // The data has been copied to the target segment so it must not be dropped.
+            if unsafe { source_segment.forget_at(datum.store as usize) } {
+                let entity = *unsafe {
+                    source_segment
+                        .entity_store()
+                        .get::<Entity>(datum.store as usize)
+                };
+                if !source_entities
+                    .get_datum_at_mut(entity.index())
+                    .expect("Entity must be valid.")
+                    .update(datum.store, datum.segment)
+                {
+                    return Err(Error::FailedToUpdate {
+                        entity: entity.index(),
+                        store: datum.store,
+                        segment: datum.segment,
+                    });
+                }
+            }
+
+            let datum = Datum {
+                generation: target.generation(),
+                store: store as u32,
+                segment: segment as u32,
+                ..Datum::DEFAULT
+            };
+            if target_entities.initialize(target.index(), datum).is_none() {
+                return Err(Error::FailedToInitialize {
+                    entity: target.index(),
+                    store: store as u32,
+                    segment: segment as u32,
+                });
+            }
+            map.insert(source, target);
+            rows.push((segment, store));
+        }
+        source_entities.release(sources.iter().copied());
+
+        for (parent, children) in sources.iter().zip(children) {
+            let parent = map[parent];
+            for child in children {
+                target_entities.adopt_last(parent, map[&child]);
+            }
+        }
+
+        let remap = Remap(map);
+        for (segment, store) in rows {
+            for target_store in target_segments[segment].stores() {
+                unsafe { target_store.remap(store, 1, &remap) };
+            }
+        }
+
+        if target_segments.len() > count {
+            target.modify();
+        }
+        if !sources.is_empty() {
+            // States of this world that refer to the transferred entities must be rescheduled.
+            self.modify();
+        }
+        Ok(remap)
+    }
+
+    /// Moves all the entities of the `source` into this `World`. Returns the mapping of the transferred entities.
+    pub fn merge(&mut self, mut source: World) -> Result<Remap> {
+        let mut parts = Parts::new(&mut source)?;
+        let (_, segments, _) = parts.get();
+        let entities: Vec<_> = segments
+            .iter()
+            .flat_map(|segment| {
+                (0..segment.count())
+                    .map(|index| *unsafe { segment.entity_store().get::<Entity>(index) })
+            })
+            .collect();
+        source.transfer(self, entities, false)
+    }
+}
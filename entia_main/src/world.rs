@@ -1,4 +1,4 @@
-use crate::{identify, resources::Resources};
+use crate::{identify, pinned::Pins, resources::Resources};
 
 // Such a 'Link' would allow to compute which components have been added or removed.
 /*
@@ -17,6 +17,13 @@ pub struct World {
     identifier: usize,
     version: usize,
     resources: Resources,
+    pins: Pins,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl World {
@@ -25,6 +32,7 @@ impl World {
             identifier: identify(),
             version: 1,
             resources: Resources::default(),
+            pins: Pins::default(),
         }
     }
 
@@ -33,9 +41,10 @@ impl World {
         self.identifier
     }
 
+    /// The version changes every time the world is modified or a resource is added or removed.
     #[inline]
     pub const fn version(&self) -> usize {
-        self.version
+        self.version + self.resources.version()
     }
 
     #[inline]
@@ -43,6 +52,11 @@ impl World {
         &mut self.resources
     }
 
+    #[inline]
+    pub(crate) fn pins(&mut self) -> &mut Pins {
+        &mut self.pins
+    }
+
     #[inline]
     pub fn modify(&mut self) {
         self.version += 1;
@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use quote::__private::Span;
+use proc_macro2::Span;
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::token::SelfType;
@@ -13,20 +13,42 @@ use syn::{parse_macro_input, Data, DataStruct};
 
 #[proc_macro_derive(Resource)]
 pub fn resource(input: TokenStream) -> TokenStream {
-    data(input, ["entia", "resource", "Resource"])
+    // The 'meta' must be generated for the concrete type such that optional modules (such as 'Default') are detected.
+    data(
+        input,
+        ["entia", "resource", "Resource"],
+        quote! {
+            fn meta() -> entia::meta::Meta {
+                entia::meta!(Self)
+            }
+        },
+    )
 }
 
 #[proc_macro_derive(Component)]
 pub fn component(input: TokenStream) -> TokenStream {
-    data(input, ["entia", "component", "Component"])
+    // The 'meta' must be generated for the concrete type such that optional modules (such as 'Deserialize') are detected.
+    data(
+        input,
+        ["entia", "component", "Component"],
+        quote! {
+            fn meta() -> entia::meta::Meta {
+                entia::meta!(Self)
+            }
+        },
+    )
 }
 
 #[proc_macro_derive(Message)]
 pub fn message(input: TokenStream) -> TokenStream {
-    data(input, ["entia", "message", "Message"])
+    data(input, ["entia", "message", "Message"], quote! {})
 }
 
-fn data<'a>(input: TokenStream, path: impl IntoIterator<Item = &'a str>) -> TokenStream {
+fn data<'a>(
+    input: TokenStream,
+    path: impl IntoIterator<Item = &'a str>,
+    body: proc_macro2::TokenStream,
+) -> TokenStream {
     let DeriveInput {
         ident, generics, ..
     } = parse_macro_input!(input as DeriveInput);
@@ -46,7 +68,7 @@ fn data<'a>(input: TokenStream, path: impl IntoIterator<Item = &'a str>) -> Toke
     ));
     let code = quote! {
         #[automatically_derived]
-        impl #impl_generics #path for #ident #type_generics #where_clauses { }
+        impl #impl_generics #path for #ident #type_generics #where_clauses { #body }
     };
     code.into()
 }
@@ -465,7 +487,7 @@ fn sync_bound(span: Span) -> TypeParamBound {
 }
 
 fn static_bound(span: Span) -> TypeParamBound {
-    TypeParamBound::Lifetime(Lifetime::new("'static".into(), span))
+    TypeParamBound::Lifetime(Lifetime::new("'static", span))
 }
 
 fn trait_bound(path: Path) -> TypeParamBound {
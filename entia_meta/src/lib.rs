@@ -1,3 +1,5 @@
+#![allow(clippy::type_complexity)]
+
 pub mod enumeration;
 pub mod field;
 pub mod function;
@@ -23,7 +25,6 @@ pub use self::{
     value::Value,
     variant::Variant,
 };
-use entia_meta_macro::meta_extern;
 pub use entia_meta_macro::{meta, Meta};
 
 // pub const PHANTOM_DATA: Module = meta_extern!((
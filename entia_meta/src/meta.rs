@@ -223,10 +223,11 @@ impl<T: Meta<Data> + 'static> Meta<&'static Enumeration> for Option<T> {
                                 get: Some(|instance| Some(instance.downcast_ref::<Self>()?)),
                                 get_mut: Some(|instance| Some(instance.downcast_mut::<Self>()?)),
                                 set: Some(|instance, value| {
-                                    Some(swap(
+                                    swap(
                                         instance.downcast_mut::<Self>()?.as_mut()?,
                                         value.downcast_mut()?,
-                                    ))
+                                    );
+                                    Some(())
                                 }),
                                 attributes: &[],
                             }],
@@ -61,7 +61,10 @@ impl Primitive {
     #[inline]
     pub fn clone(&self, value: &dyn Any) -> Option<Value> {
         Some(match self.kind {
-            Primitives::Unit => Value::Unit(*value.downcast_ref()?),
+            Primitives::Unit => {
+                value.downcast_ref::<()>()?;
+                Value::Unit(())
+            }
             Primitives::Bool => Value::Bool(*value.downcast_ref()?),
             Primitives::Char => Value::Char(*value.downcast_ref()?),
             Primitives::U8 => Value::U8(*value.downcast_ref()?),
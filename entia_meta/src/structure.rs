@@ -20,6 +20,7 @@ pub struct Structure {
 
 impl Structure {
     #[inline]
+    #[allow(clippy::new_ret_no_self)]
     pub fn new<I: IntoIterator<Item = Value>>(&self, parameters: I) -> Option<Box<dyn Any>> {
         (self.new?)(&mut parameters.into_iter())
     }
@@ -40,7 +41,7 @@ impl Structure {
     #[inline]
     pub fn from(&'static self, value: Value) -> Option<Value> {
         match value {
-            _ if self.fields.len() == 0 => Some(Value::Structure(self.new([])?, self)),
+            _ if self.fields.is_empty() => Some(Value::Structure(self.new([])?, self)),
             Value::Structure(value, source) => {
                 let mut values = source.values(value).ok()?.into_vec();
                 for (i, field) in source.fields.iter().enumerate() {
@@ -7,7 +7,7 @@ use crate::{
 };
 use std::{
     any::{Any, TypeId},
-    mem::{forget, transmute_copy},
+    mem::transmute_copy,
     ops::{Deref, DerefMut},
 };
 
@@ -38,7 +38,7 @@ impl Value {
     pub fn from<T: Meta<Data> + 'static>(value: T) -> Self {
         match T::meta() {
             Data::Primitive(primitive) => match primitive.kind {
-                Primitives::Unit => Self::Unit(unsafe { transmute_copy(&value) }),
+                Primitives::Unit => Self::Unit(()),
                 Primitives::Bool => Self::Bool(unsafe { transmute_copy(&value) }),
                 Primitives::Char => Self::Char(unsafe { transmute_copy(&value) }),
                 Primitives::U8 => Self::U8(unsafe { transmute_copy(&value) }),
@@ -92,11 +92,8 @@ impl Value {
         #[inline]
         fn cast<S: Copy + 'static, T: 'static>(source: S) -> Result<T, S> {
             if source.type_id() == TypeId::of::<T>() {
-                // SAFETY: Since 'T' == 'U', they will have the same size.
-                let target = unsafe { transmute_copy(&source) };
-                // Prevent double drop of 'source' since there now exists a reinterpret version of it that will take care of dropping.
-                forget(source);
-                Ok(target)
+                // SAFETY: Since 'S' == 'T', they will have the same size and since 'S' is 'Copy', no drop is duplicated.
+                Ok(unsafe { transmute_copy(&source) })
             } else {
                 Err(source)
             }
@@ -157,6 +154,7 @@ impl Value {
     }
 
     #[inline]
+    #[allow(clippy::should_implement_trait)]
     pub fn clone(&self) -> Option<Self> {
         self.meta().clone(self)
     }
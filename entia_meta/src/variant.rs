@@ -15,6 +15,7 @@ pub struct Variant {
 
 impl Variant {
     #[inline]
+    #[allow(clippy::new_ret_no_self)]
     pub fn new<I: IntoIterator<Item = Value>>(&self, parameters: I) -> Option<Box<dyn Any>> {
         (self.new?)(&mut parameters.into_iter())
     }
@@ -158,9 +158,10 @@ pub fn meta(attribute: TokenStream, item: TokenStream) -> TokenStream {
     let context = if attribute.is_empty() {
         Context::default()
     } else {
-        let mut context = Context::default();
-        context.meta_path = parse_macro_input!(attribute as Path);
-        context
+        Context {
+            meta_path: parse_macro_input!(attribute as Path),
+            ..Context::default()
+        }
     };
 
     match parse_macro_input!(item as Item) {
@@ -214,6 +215,7 @@ impl Context {
             .to_token_stream()
     }
 
+    #[allow(dead_code)]
     pub fn item(&self, item: &mut Item) -> Option<impl ToTokens> {
         match item {
             Item::Const(item) => Some(self.constant(item).to_token_stream()),
@@ -234,7 +236,7 @@ impl Context {
             Visibility::Public(_) => quote! { #meta_path::Access::Public },
             Visibility::Crate(_) => quote! { #meta_path::Access::Crate },
             Visibility::Restricted(restricted) => {
-                if let Some(_) = restricted.in_token {
+                if restricted.in_token.is_some() {
                     quote! { #meta_path::Access::Super }
                 } else {
                     quote! { #meta_path::Access::Crate }
@@ -246,9 +248,8 @@ impl Context {
 
     pub fn attribute(&self, Attribute { path, tokens, .. }: &Attribute) -> Option<impl ToTokens> {
         if let Some(ident) = path.get_ident() {
-            match ident.to_string().as_str() {
-                "doc" => return None,
-                _ => {}
+            if ident == "doc" {
+                return None;
             }
         }
         let meta_path = &self.meta_path;
@@ -270,6 +271,7 @@ impl Context {
         context
     }
 
+    #[allow(dead_code)]
     pub fn pop(&self) -> Self {
         let mut context = self.clone();
         if context.external {
@@ -614,7 +616,7 @@ impl Context {
             )
         };
         let (_, _, new, values, fields) =
-            self.fields(fields, &parent, &generics, &parse_quote!(#ident));
+            self.fields(fields, &parent, generics, &parse_quote!(#ident));
         let generics = generics.params.iter().map(|generic| self.generic(generic));
         quote_spanned!(ident.span() => #meta_path::Structure {
             access: #access,
@@ -658,7 +660,7 @@ impl Context {
                 let (_, deconstruct, new, values, fields) = self.fields(
                     &variant.fields,
                     &parent,
-                    &generics,
+                    generics,
                     &parse_quote!(#ident::#name),
                 );
                 (
@@ -773,9 +775,9 @@ impl Context {
         }: &Signature,
     ) -> impl ToTokens {
         let meta_path = &self.meta_path;
-        let modifiers = asyncness.map_or(0 as u8, |_| 1 << 0)
-            | constness.map_or(0 as u8, |_| 1 << 1)
-            | unsafety.map_or(0 as u8, |_| 1 << 2);
+        let modifiers = asyncness.map_or(0_u8, |_| 1 << 0)
+            | constness.map_or(0_u8, |_| 1 << 1)
+            | unsafety.map_or(0_u8, |_| 1 << 2);
         let generics = generics.params.iter().map(|generic| self.generic(generic));
         let parameters = inputs
             .iter()
@@ -886,17 +888,7 @@ impl Context {
         })
     }
 
-    pub fn union(
-        &self,
-        ItemUnion {
-            attrs,
-            vis,
-            ident,
-            generics,
-            fields,
-            ..
-        }: &ItemUnion,
-    ) -> impl ToTokens {
+    pub fn union(&self, ItemUnion { .. }: &ItemUnion) -> impl ToTokens {
         quote! {}
     }
 
@@ -11,6 +11,7 @@ pub struct New<T: ?Sized>(PhantomData<T>);
 
 impl<T: ?Sized> New<T> {
     #[inline]
+    #[allow(clippy::self_named_constructors)]
     pub const fn new() -> Self {
         Self(PhantomData)
     }
@@ -20,7 +21,7 @@ impl<T: ?Sized> Copy for New<T> {}
 impl<T: ?Sized> Clone for New<T> {
     #[inline]
     fn clone(&self) -> Self {
-        Self(PhantomData)
+        *self
     }
 }
 impl<T: ?Sized> Default for New<T> {
@@ -61,13 +62,11 @@ impl<T: Deserialize, const N: usize> Deserialize for [T; N] {
     fn deserialize<D: Deserializer>(self, deserializer: D) -> Result<Self::Value, D::Error> {
         let mut list = deserializer.list()?;
         let mut values = [(); N].map(|_| None);
-        let mut index = 0;
-        for value in self {
+        for (index, value) in self.into_iter().enumerate() {
             values[index] = Some(match list.item()? {
                 Some(item) => item.value(value)?,
                 None => list.miss(value)?,
             });
-            index += 1;
         }
         list.drain()?;
         Ok(values.map(Option::unwrap))
@@ -90,7 +90,7 @@ pub trait Map {
     }
 
     #[inline]
-    fn drain<K: Deserialize>(mut self, key: K) -> Result<(), Self::Error>
+    fn drain<K: Deserialize>(self, _key: K) -> Result<(), Self::Error>
     where
         Self: Sized,
     {
@@ -238,7 +238,7 @@ pub mod node {
         #[inline]
         fn variant<K: Deserialize>(self, key: K) -> Result<(K::Value, Self::Variant), Self::Error> {
             match self.0 {
-                Node::Object(pairs) if pairs.len() > 0 => {
+                Node::Object(pairs) if !pairs.is_empty() => {
                     let pair = &pairs[0];
                     let key = key.deserialize(NodeDeserializer(&pair.0))?;
                     Ok((key, self))
@@ -326,3 +326,205 @@ pub mod node {
         }
     }
 }
+
+pub mod serialize {
+    use crate::{
+        meta::{self, Meta},
+        serialize::Serialize,
+        serializer::{self, Serializer},
+    };
+    use std::fmt::{self, Write};
+
+    /// Writes the serialized value as JSON text. Maps with keys that are not strings have their keys converted to
+    /// strings and enumerations are written as `"Variant"` or `{ "Variant": value }`.
+    pub struct JsonSerializer<'a>(&'a mut String);
+    pub struct ListSerializer<'a>(&'a mut String, bool, &'static str);
+    pub struct MapSerializer<'a>(&'a mut String, bool, &'static str);
+    pub struct VariantSerializer<'a>(&'a mut String, String);
+
+    pub type Error = fmt::Error;
+
+    impl<'a> JsonSerializer<'a> {
+        #[inline]
+        pub fn new(buffer: &'a mut String) -> Self {
+            Self(buffer)
+        }
+
+        pub fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+            let mut buffer = String::new();
+            value.serialize(JsonSerializer(&mut buffer))?;
+            Ok(buffer)
+        }
+    }
+
+    fn escape(buffer: &mut String, value: &str) -> Result<(), Error> {
+        buffer.push('"');
+        for character in value.chars() {
+            match character {
+                '"' => buffer.push_str("\\\""),
+                '\\' => buffer.push_str("\\\\"),
+                '\n' => buffer.push_str("\\n"),
+                '\r' => buffer.push_str("\\r"),
+                '\t' => buffer.push_str("\\t"),
+                character if character.is_control() => {
+                    write!(buffer, "\\u{:04x}", character as u32)?
+                }
+                character => buffer.push(character),
+            }
+        }
+        buffer.push('"');
+        Ok(())
+    }
+
+    impl<'a> Serializer for JsonSerializer<'a> {
+        type Value = ();
+        type Error = Error;
+        type Map = MapSerializer<'a>;
+        type List = ListSerializer<'a>;
+        type Structure = Self;
+        type Enumeration = Self;
+
+        fn unit(self) -> Result<Self::Value, Self::Error> {
+            self.0.push_str("null");
+            Ok(())
+        }
+        fn bool(self, value: bool) -> Result<Self::Value, Self::Error> {
+            write!(self.0, "{}", value)
+        }
+        fn char(self, value: char) -> Result<Self::Value, Self::Error> {
+            escape(self.0, value.encode_utf8(&mut [0; 4]))
+        }
+        fn u128(self, value: u128) -> Result<Self::Value, Self::Error> {
+            write!(self.0, "{}", value)
+        }
+        fn i128(self, value: i128) -> Result<Self::Value, Self::Error> {
+            write!(self.0, "{}", value)
+        }
+        fn f64(self, value: f64) -> Result<Self::Value, Self::Error> {
+            if value.is_finite() {
+                write!(self.0, "{}", value)
+            } else {
+                self.unit()
+            }
+        }
+        fn string(self, value: &str) -> Result<Self::Value, Self::Error> {
+            escape(self.0, value)
+        }
+        fn list(self) -> Result<Self::List, Self::Error> {
+            self.0.push('[');
+            Ok(ListSerializer(self.0, true, "]"))
+        }
+        fn map(self) -> Result<Self::Map, Self::Error> {
+            self.0.push('{');
+            Ok(MapSerializer(self.0, true, "}"))
+        }
+        fn structure<T: Meta<meta::Structure>>(self) -> Result<Self::Structure, Self::Error> {
+            Ok(self)
+        }
+        fn enumeration<T: Meta<meta::Enumeration>>(self) -> Result<Self::Enumeration, Self::Error> {
+            Ok(self)
+        }
+    }
+
+    impl<'a> serializer::List for ListSerializer<'a> {
+        type Value = ();
+        type Error = Error;
+
+        fn item<T: Serialize>(mut self, item: T) -> Result<Self, Self::Error> {
+            if !self.1 {
+                self.0.push(',');
+            }
+            self.1 = false;
+            item.serialize(JsonSerializer(&mut *self.0))?;
+            Ok(self)
+        }
+
+        fn end(self) -> Result<Self::Value, Self::Error> {
+            self.0.push_str(self.2);
+            Ok(())
+        }
+    }
+
+    impl<'a> serializer::Map for MapSerializer<'a> {
+        type Value = ();
+        type Error = Error;
+
+        fn pair<K: Serialize, V: Serialize>(
+            mut self,
+            key: K,
+            value: V,
+        ) -> Result<Self, Self::Error> {
+            if !self.1 {
+                self.0.push(',');
+            }
+            self.1 = false;
+            let key = JsonSerializer::serialize(&key)?;
+            if key.starts_with('"') {
+                self.0.push_str(&key);
+            } else {
+                escape(self.0, &key)?;
+            }
+            self.0.push(':');
+            value.serialize(JsonSerializer(&mut *self.0))?;
+            Ok(self)
+        }
+
+        fn end(self) -> Result<Self::Value, Self::Error> {
+            self.0.push_str(self.2);
+            Ok(())
+        }
+    }
+
+    impl<'a> serializer::Structure for JsonSerializer<'a> {
+        type Value = ();
+        type Error = Error;
+        type Map = MapSerializer<'a>;
+        type List = ListSerializer<'a>;
+
+        fn unit(self) -> Result<Self::Value, Self::Error> {
+            Serializer::unit(self)
+        }
+        fn tuple(self) -> Result<Self::List, Self::Error> {
+            Serializer::list(self)
+        }
+        fn map(self) -> Result<Self::Map, Self::Error> {
+            Serializer::map(self)
+        }
+    }
+
+    impl<'a> serializer::Enumeration for JsonSerializer<'a> {
+        type Value = ();
+        type Error = Error;
+        type Structure = VariantSerializer<'a>;
+
+        fn never(self) -> Result<Self::Value, Self::Error> {
+            Serializer::unit(self)
+        }
+
+        fn variant(self, name: &str, _: usize) -> Result<Self::Structure, Self::Error> {
+            let mut variant = String::new();
+            escape(&mut variant, name)?;
+            Ok(VariantSerializer(self.0, variant))
+        }
+    }
+
+    impl<'a> serializer::Structure for VariantSerializer<'a> {
+        type Value = ();
+        type Error = Error;
+        type Map = MapSerializer<'a>;
+        type List = ListSerializer<'a>;
+
+        fn unit(self) -> Result<Self::Value, Self::Error> {
+            self.0.push_str(&self.1);
+            Ok(())
+        }
+        fn tuple(self) -> Result<Self::List, Self::Error> {
+            write!(self.0, "{{{}:[", self.1)?;
+            Ok(ListSerializer(self.0, true, "]}"))
+        }
+        fn map(self) -> Result<Self::Map, Self::Error> {
+            write!(self.0, "{{{}:{{", self.1)?;
+            Ok(MapSerializer(self.0, true, "}}"))
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::type_complexity)]
+
 pub mod deserialize;
 pub mod deserializer;
 pub mod json;
@@ -87,7 +89,7 @@ mod example {
         type Value = Fett;
 
         fn deserialize<D: Deserializer>(self, deserializer: D) -> Result<Self::Value, D::Error> {
-            let key = &mut [0 as u8];
+            let key = &mut [0_u8];
             let (key, variant) = deserializer.enumeration()?.variant(&mut key[..])?;
             match &*key {
                 b"A" => {
@@ -117,7 +119,7 @@ mod example {
 
         #[inline]
         fn deserialize<D: Deserializer>(self, deserializer: D) -> Result<Self::Value, D::Error> {
-            let key = &mut [0 as u8];
+            let key = &mut [0_u8];
             let (key, variant) = deserializer.enumeration()?.variant(&mut key[..])?;
             match &*key {
                 b"A" => {
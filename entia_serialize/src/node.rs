@@ -63,6 +63,8 @@ pub enum Enumeration {
 #[derive(Clone, Debug)]
 pub enum Error {
     Invalid,
+    /// A map or structure held a key that the deserialized value does not know.
+    Excess,
 }
 
 impl From<Node> for Structure {
@@ -142,6 +144,20 @@ macro_rules! number {
     ($($t:ident),*) => { $(number!($t);)* }
 }
 
+impl Items {
+    #[inline]
+    pub const fn new(items: Vec<Node>) -> Self {
+        Self(items)
+    }
+}
+
+impl Pairs {
+    #[inline]
+    pub const fn new(pairs: Vec<(Node, Node)>) -> Self {
+        Self(pairs)
+    }
+}
+
 impl Node {
     #[inline]
     pub fn bool(&self) -> Option<bool> {
@@ -158,6 +174,39 @@ impl Node {
             node => char::from_u32(node.u32()?),
         }
     }
+
+    #[inline]
+    pub fn string(&self) -> Option<&str> {
+        match self {
+            Node::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn items(&self) -> Option<&[Node]> {
+        match self {
+            Node::List(Items(items))
+            | Node::Slice(Items(items))
+            | Node::Tuple(Items(items))
+            | Node::Structure(Structure::Tuple(Items(items))) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn pairs(&self) -> Option<&[(Node, Node)]> {
+        match self {
+            Node::Map(Pairs(pairs)) | Node::Structure(Structure::Map(Pairs(pairs))) => Some(pairs),
+            _ => None,
+        }
+    }
+
+    /// Returns the value that is paired with the string `key` in a map.
+    pub fn get(&self, key: &str) -> Option<&Node> {
+        self.pairs()?
+            .iter()
+            .find(|(name, _)| name.string() == Some(key))
+            .map(|(_, value)| value)
+    }
 }
 
 from!(char, bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
@@ -300,7 +349,7 @@ pub mod serialize {
         }
 
         fn bytes(self, value: &[u8]) -> Result<Self::Value, Self::Error> {
-            Ok(Node::Bytes(value.iter().copied().collect()))
+            Ok(Node::Bytes(value.to_vec()))
         }
         fn string(self, value: &str) -> Result<Self::Value, Self::Error> {
             Ok(Node::String(value.into()))
@@ -427,7 +476,7 @@ pub mod deserialize {
     impl Deserialize for New<Node> {
         type Value = Node;
 
-        fn deserialize<D: Deserializer>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        fn deserialize<D: Deserializer>(self, _deserializer: D) -> Result<Self::Value, D::Error> {
             todo!()
         }
     }
@@ -655,6 +704,7 @@ pub mod deserialize {
             let item = match &self.0 {
                 Node::List(Items(nodes))
                 | Node::Slice(Items(nodes))
+                | Node::Tuple(Items(nodes))
                 | Node::Structure(Structure::Tuple(Items(nodes)))
                 | Node::Enumeration(Enumeration::Variant(_, _, Structure::Tuple(Items(nodes)))) => {
                     nodes.get(self.1).map(|node| NodeDeserializer(node.clone()))
@@ -669,6 +719,11 @@ pub mod deserialize {
                 Node::Bytes(value) => value
                     .get(self.1)
                     .map(|&value| NodeDeserializer(Node::U8(value))),
+                // A string is a list of characters (such as for `&mut String`) and the index is a byte offset in it.
+                Node::String(value) => value[self.1..].chars().next().map(|value| {
+                    self.1 += value.len_utf8() - 1;
+                    NodeDeserializer(Node::Char(value))
+                }),
                 node if self.1 == 0 => Some(NodeDeserializer(node.clone())),
                 _ => None,
             };
@@ -687,6 +742,10 @@ pub mod deserialize {
         fn value<V: Deserialize>(self, value: V) -> Result<V::Value, Self::Error> {
             value.deserialize(NodeDeserializer(self.0))
         }
+
+        fn excess(self) -> Result<(), Self::Error> {
+            Err(Error::Excess)
+        }
     }
 
     impl deserializer::Map for ChildDeserializer {
@@ -700,6 +759,7 @@ pub mod deserialize {
             let pair = match &self.0 {
                 Node::List(Items(nodes))
                 | Node::Slice(Items(nodes))
+                | Node::Tuple(Items(nodes))
                 | Node::Structure(Structure::Tuple(Items(nodes)))
                 | Node::Enumeration(Enumeration::Variant(_, _, Structure::Tuple(Items(nodes)))) => {
                     match nodes.get(self.1) {
@@ -796,7 +856,17 @@ mod tests {
 
     #[test]
     fn boba_to_fett() -> Result<(), Error> {
-        Boba(true).convert(New::<Fett>::new()).map(|_| ())
+        let fett = Boba(true).convert(New::<Fett>::new())?;
+        assert!(fett.0);
+        Ok(())
+    }
+
+    #[test]
+    fn str_to_string() -> Result<(), Error> {
+        let mut value = String::from("fett");
+        "bobà".convert(&mut value)?;
+        assert_eq!(value, "bobà");
+        Ok(())
     }
 }
 
@@ -11,14 +11,14 @@ pub trait Serialize {
 impl<T: Serialize + ?Sized> Serialize for &T {
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
-        (&**self).serialize(serializer)
+        (**self).serialize(serializer)
     }
 }
 
 impl<T: Serialize + ?Sized> Serialize for &mut T {
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Value, S::Error> {
-        (&**self).serialize(serializer)
+        (**self).serialize(serializer)
     }
 }
 